@@ -0,0 +1,182 @@
+use std::{collections::HashMap, fmt, path::Path};
+
+use serde::Deserialize;
+
+use crate::{Answer, Error, Part, read_input};
+
+/// where runners look for expected answers when `--answers` isn't given
+pub const DEFAULT_ANSWERS_PATH: &str = "answers.toml";
+
+/// Known-good answers, loaded from a toml file like:
+///
+/// ```toml
+/// [[answer]]
+/// day = 1
+/// part = 1
+/// expected = 1234
+/// ```
+#[derive(Debug, Default)]
+pub struct Answers {
+    expected: HashMap<(u8, Part), String>,
+}
+
+#[derive(Deserialize)]
+struct AnswersFile {
+    #[serde(default)]
+    answer: Vec<Entry>,
+}
+
+#[derive(Deserialize)]
+struct Entry {
+    day: u8,
+    part: u8,
+    expected: toml::Value,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Status {
+    Pass,
+    Fail { expected: String },
+    Unknown,
+}
+
+impl fmt::Display for Status {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Status::Pass => write!(f, "PASS"),
+            Status::Fail { expected } => write!(f, "FAIL (expected {expected})"),
+            Status::Unknown => write!(f, "UNKNOWN"),
+        }
+    }
+}
+
+impl Answers {
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, Error> {
+        let path = path.as_ref();
+        let invalid = |message: String| Error::Answers {
+            path: path.to_path_buf(),
+            message,
+        };
+
+        let file: AnswersFile =
+            toml::from_str(&read_input(path)?).map_err(|e| invalid(e.to_string()))?;
+
+        let mut expected = HashMap::new();
+        for entry in file.answer {
+            let part = entry
+                .part
+                .to_string()
+                .parse::<Part>()
+                .map_err(|e| invalid(format!("day {}: {e}", entry.day)))?;
+
+            let value = expected_value(entry.expected)
+                .map_err(|e| invalid(format!("day {} part {part}: {e}", entry.day)))?;
+
+            expected.insert((entry.day, part), value);
+        }
+
+        Ok(Self { expected })
+    }
+
+    /// load an explicit path, or the default file if there is one
+    pub fn load_optional(path: Option<&Path>) -> Result<Option<Self>, Error> {
+        match path {
+            Some(path) => Self::load(path).map(Some),
+            None if Path::new(DEFAULT_ANSWERS_PATH).exists() => {
+                Self::load(DEFAULT_ANSWERS_PATH).map(Some)
+            }
+            None => Ok(None),
+        }
+    }
+
+    pub fn insert(&mut self, day: u8, part: Part, expected: impl Into<String>) {
+        self.expected.insert((day, part), expected.into());
+    }
+
+    pub fn check(&self, answer: &Answer) -> Status {
+        match self.expected.get(&(answer.day, answer.part)) {
+            Some(expected) if *expected == answer.value => Status::Pass,
+            Some(expected) => Status::Fail {
+                expected: expected.clone(),
+            },
+            None => Status::Unknown,
+        }
+    }
+}
+
+/// numbers are the common case but some puzzles answer with text
+pub(crate) fn expected_value(value: toml::Value) -> Result<String, String> {
+    match value {
+        toml::Value::String(s) => Ok(s.trim().to_string()),
+        toml::Value::Integer(n) => Ok(n.to_string()),
+        other => Err(format!(
+            "expected should be a number or string, got {other}"
+        )),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::{env::temp_dir, fs, process};
+
+    use crate::{
+        Answer, Answers, Part,
+        answers::{AnswersFile, Status},
+    };
+
+    fn answer(day: u8, part: Part, value: &str) -> Answer {
+        Answer {
+            day,
+            part,
+            value: value.to_string(),
+            duration: None,
+        }
+    }
+
+    #[test]
+    fn it_should_check_answers() {
+        let path = temp_dir().join(format!("aoc-core-answers-test-{}.toml", process::id()));
+        fs::write(
+            &path,
+            r#"
+[[answer]]
+day = 1
+part = 1
+expected = 1234
+
+[[answer]]
+day = 1
+part = 2
+expected = "abc"
+"#,
+        )
+        .unwrap();
+        let answers = Answers::load(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+
+        assert_eq!(answers.check(&answer(1, Part::One, "1234")), Status::Pass);
+        assert_eq!(
+            answers.check(&answer(1, Part::Two, "abd")),
+            Status::Fail {
+                expected: "abc".to_string()
+            }
+        );
+        assert_eq!(answers.check(&answer(2, Part::One, "1")), Status::Unknown);
+    }
+
+    #[test]
+    fn it_should_parse_an_empty_file() {
+        let file: AnswersFile = toml::from_str("").unwrap();
+        assert!(file.answer.is_empty());
+    }
+
+    #[test]
+    fn it_should_reject_bad_parts() {
+        let path = temp_dir().join(format!("aoc-core-bad-answers-test-{}.toml", process::id()));
+        fs::write(&path, "[[answer]]\nday = 1\npart = 3\nexpected = 1\n").unwrap();
+        let err = Answers::load(&path).unwrap_err();
+        fs::remove_file(&path).unwrap();
+
+        assert!(err.to_string().contains("part must be 1 or 2"));
+    }
+}
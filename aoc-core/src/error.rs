@@ -0,0 +1,76 @@
+use std::{fmt, io, path::PathBuf};
+
+use crate::ParseError;
+
+#[derive(Debug)]
+pub enum Error {
+    /// bad command line arguments
+    Usage(String),
+    /// couldn't read the input file
+    Io { path: PathBuf, source: io::Error },
+    /// the answers file isn't valid
+    Answers { path: PathBuf, message: String },
+    /// an example file isn't laid out as expectations, `---`, then the input
+    Example { path: PathBuf, message: String },
+    /// the puzzle input isn't what the day expected
+    Parse(ParseError),
+    /// the workspace isn't laid out the way `aoc new` expects
+    Workspace(String),
+    /// couldn't download an input
+    Fetch { day: u8, message: String },
+    /// a cached input doesn't match the checksum saved when it was downloaded
+    Checksum { path: PathBuf },
+}
+
+impl Error {
+    /// for output that couldn't be written
+    pub fn stdout(source: io::Error) -> Self {
+        Error::Io {
+            path: PathBuf::from("stdout"),
+            source,
+        }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Usage(msg) => write!(f, "usage: {msg}"),
+            Error::Io { path, source } => {
+                write!(f, "failed to read {}: {source}", path.display())
+            }
+            Error::Answers { path, message } => {
+                write!(f, "invalid answers file {}: {message}", path.display())
+            }
+            Error::Example { path, message } => {
+                write!(f, "invalid example {}: {message}", path.display())
+            }
+            Error::Parse(e) => write!(f, "{e}"),
+            Error::Workspace(msg) => write!(f, "{msg}"),
+            Error::Fetch { day, message } => {
+                write!(f, "couldn't fetch the input for day {day}: {message}")
+            }
+            Error::Checksum { path } => write!(
+                f,
+                "{} has changed since it was downloaded, delete it to fetch it again",
+                path.display()
+            ),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Io { source, .. } => Some(source),
+            Error::Parse(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<ParseError> for Error {
+    fn from(e: ParseError) -> Self {
+        Error::Parse(e)
+    }
+}
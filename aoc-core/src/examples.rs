@@ -0,0 +1,269 @@
+use std::{
+    collections::HashMap,
+    env, fs,
+    path::{Path, PathBuf},
+};
+
+use crate::{Answers, Error, Part, Solution, answers::Status, find_workspace, read_input, solve};
+
+/// where runners look for example files, relative to the workspace root
+pub const DEFAULT_EXAMPLES_DIR: &str = "examples";
+
+/// A small input with known answers, usually the one from the puzzle text
+///
+/// Kept in `examples/dayNN/<name>.txt` with the expected answers at the top:
+///
+/// ```text
+/// part1 = 3
+/// part2 = 6
+/// ---
+/// L68
+/// L30
+/// ```
+///
+/// Either part can be left out if the example only covers one of them.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Example {
+    /// the file name without `.txt`
+    pub name: String,
+    pub input: String,
+    expected: HashMap<Part, String>,
+}
+
+impl Example {
+    pub fn new(name: impl Into<String>, input: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            input: input.into(),
+            expected: HashMap::new(),
+        }
+    }
+
+    pub fn expect(mut self, part: Part, expected: impl Into<String>) -> Self {
+        self.expected.insert(part, expected.into());
+        self
+    }
+
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, Error> {
+        let path = path.as_ref();
+        let invalid = |message: String| Error::Example {
+            path: path.to_path_buf(),
+            message,
+        };
+
+        let text = read_input(path)?;
+        let (header, input) = text
+            .split_once("\n---\n")
+            .or_else(|| text.split_once("\r\n---\r\n"))
+            .ok_or_else(|| invalid("missing a '---' line between answers and input".into()))?;
+
+        let name = path
+            .file_stem()
+            .map_or_else(String::new, |name| name.to_string_lossy().into_owned());
+        let mut example = Self::new(name, input);
+
+        let header: toml::Table = toml::from_str(header).map_err(|e| invalid(e.to_string()))?;
+        for (key, value) in header {
+            let part = match key.as_str() {
+                "part1" => Part::One,
+                "part2" => Part::Two,
+                _ => return Err(invalid(format!("expected part1 or part2, got '{key}'"))),
+            };
+            let value = crate::answers::expected_value(value)
+                .map_err(|e| invalid(format!("part {part}: {e}")))?;
+            example.expected.insert(part, value);
+        }
+
+        Ok(example)
+    }
+
+    pub fn expected(&self, part: Part) -> Option<&str> {
+        self.expected.get(&part).map(String::as_str)
+    }
+
+    /// the expectations as an answers file, so runners can check examples like real inputs
+    pub fn answers(&self, day: u8) -> Answers {
+        let mut answers = Answers::default();
+        for (part, expected) in &self.expected {
+            answers.insert(day, *part, expected.clone());
+        }
+        answers
+    }
+}
+
+/// [`DEFAULT_EXAMPLES_DIR`] in the workspace around the current directory, so examples are
+/// found from anywhere inside it (outside of one it's just relative to where you are)
+pub fn examples_dir() -> PathBuf {
+    env::current_dir()
+        .ok()
+        .and_then(|cwd| find_workspace(&cwd))
+        .map_or_else(
+            || PathBuf::from(DEFAULT_EXAMPLES_DIR),
+            |root| root.join(DEFAULT_EXAMPLES_DIR),
+        )
+}
+
+/// every `*.txt` in `dir/dayNN`, sorted by name
+/// a day without any examples gets an empty list
+pub fn load_examples(dir: impl AsRef<Path>, day: u8) -> Result<Vec<Example>, Error> {
+    let dir = dir.as_ref().join(format!("day{day:02}"));
+    if !dir.exists() {
+        return Ok(Vec::new());
+    }
+
+    let io_error = |source| Error::Io {
+        path: dir.clone(),
+        source,
+    };
+    let mut paths = fs::read_dir(&dir)
+        .map_err(io_error)?
+        .map(|entry| entry.map(|entry| entry.path()))
+        .collect::<Result<Vec<PathBuf>, _>>()
+        .map_err(io_error)?;
+    paths.retain(|path| path.extension().is_some_and(|ext| ext == "txt"));
+    paths.sort();
+
+    paths.iter().map(Example::load).collect()
+}
+
+/// run `S` over every example in `dir` (streamed as well, if it can)
+/// and panic with everything that didn't match
+/// used by [`example_files!`](crate::example_files)
+pub fn assert_examples<S: Solution>(dir: impl AsRef<Path>) {
+    let dir = dir.as_ref();
+    let examples = load_examples(dir, S::DAY).expect("examples should load");
+    assert!(
+        !examples.is_empty(),
+        "no examples for day {} in {}",
+        S::DAY,
+        dir.display()
+    );
+
+    let mut failures = Vec::new();
+    for example in &examples {
+        let solved = solve::<S>(&example.input, &Part::ALL).map_err(Error::from);
+        // days that can stream should get the same answers that way too
+        let streamed = S::STREAM.map(|stream| stream(&mut example.input.as_bytes(), &Part::ALL));
+
+        for (how, report) in [("", Some(solved)), (" (streamed)", streamed)] {
+            let report = match report {
+                Some(Ok(report)) => report,
+                Some(Err(e)) => {
+                    failures.push(format!("{}{how}: {e}", example.name));
+                    continue;
+                }
+                None => continue,
+            };
+
+            let answers = example.answers(S::DAY);
+            for answer in &report.answers {
+                if let Status::Fail { expected } = answers.check(answer) {
+                    failures.push(format!(
+                        "{}{how} {answer}, expected {expected}",
+                        example.name
+                    ));
+                }
+            }
+        }
+    }
+
+    assert!(failures.is_empty(), "{}", failures.join("\n"));
+}
+
+/// Tests for a [`Solution`] against inline examples
+///
+/// Each example gets its own module with a `part1` and `part2` test:
+///
+/// ```ignore
+/// aoc_core::examples! {
+///     Day01;
+///     large_rotations: "L50\nR200" => (2, 3),
+/// }
+/// ```
+#[macro_export]
+macro_rules! examples {
+    ($solution:ty; $($name:ident: $input:expr => ($part1:expr, $part2:expr)),+ $(,)?) => {
+        $(
+            mod $name {
+                #[allow(unused_imports)]
+                use super::*;
+
+                #[test]
+                fn part1() {
+                    let input = <$solution as $crate::Solution>::parse($input)
+                        .expect("example should parse");
+                    assert_eq!(<$solution as $crate::Solution>::part1(&input), $part1);
+                }
+
+                #[test]
+                fn part2() {
+                    let input = <$solution as $crate::Solution>::parse($input)
+                        .expect("example should parse");
+                    assert_eq!(<$solution as $crate::Solution>::part2(&input), $part2);
+                }
+            }
+        )+
+    };
+}
+
+/// A test that runs a [`Solution`] over every file in `examples/dayNN/`
+/// (relative to the workspace root, which is the parent of the day's crate)
+#[macro_export]
+macro_rules! example_files {
+    ($solution:ty) => {
+        #[test]
+        fn it_should_solve_the_example_files() {
+            $crate::examples::assert_examples::<$solution>(concat!(
+                env!("CARGO_MANIFEST_DIR"),
+                "/../",
+                "examples"
+            ));
+        }
+    };
+}
+
+#[cfg(test)]
+mod test {
+    use std::{env::temp_dir, fs, process};
+
+    use crate::{
+        Error, Part,
+        examples::{Example, load_examples},
+    };
+
+    #[test]
+    fn it_should_load_examples_in_order() {
+        let root = temp_dir().join(format!("aoc-core-examples-{}", process::id()));
+        let dir = root.join("day01");
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("b.txt"), "part2 = \"abc\"\n---\nR5\n").unwrap();
+        fs::write(dir.join("a.txt"), "part1 = 3\npart2 = 6\n---\nL68\nL30\n").unwrap();
+        fs::write(dir.join("notes.md"), "not an example").unwrap();
+
+        let examples = load_examples(&root, 1).unwrap();
+        let missing = load_examples(&root, 2).unwrap();
+        fs::remove_dir_all(&root).unwrap();
+
+        assert_eq!(
+            examples,
+            vec![
+                Example::new("a", "L68\nL30\n")
+                    .expect(Part::One, "3")
+                    .expect(Part::Two, "6"),
+                Example::new("b", "R5\n").expect(Part::Two, "abc"),
+            ]
+        );
+        assert!(missing.is_empty());
+    }
+
+    #[test]
+    fn it_should_reject_examples_without_a_separator() {
+        let path = temp_dir().join(format!("aoc-core-bad-example-{}.txt", process::id()));
+        fs::write(&path, "part1 = 3\nL68\n").unwrap();
+        let err = Example::load(&path).unwrap_err();
+        fs::remove_file(&path).unwrap();
+
+        assert!(matches!(err, Error::Example { .. }));
+        assert!(err.to_string().contains("missing a '---' line"));
+    }
+}
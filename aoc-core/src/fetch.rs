@@ -0,0 +1,71 @@
+use std::path::PathBuf;
+
+use crate::{Error, read_input};
+
+/// Somewhere puzzle inputs can be downloaded from
+pub trait Fetcher {
+    fn fetch(&self, day: u8) -> Result<String, Error>;
+}
+
+/// adventofcode.com, logged in with the `AOC_SESSION` cookie
+#[cfg(feature = "http")]
+pub struct HttpFetcher {
+    session: Option<String>,
+}
+
+#[cfg(feature = "http")]
+impl HttpFetcher {
+    pub const YEAR: u16 = 2025;
+
+    pub fn new(session: impl Into<String>) -> Self {
+        Self {
+            session: Some(session.into()),
+        }
+    }
+
+    /// a missing session isn't an error until something actually needs downloading
+    pub fn from_env() -> Self {
+        Self {
+            session: std::env::var("AOC_SESSION").ok(),
+        }
+    }
+}
+
+#[cfg(feature = "http")]
+impl Fetcher for HttpFetcher {
+    fn fetch(&self, day: u8) -> Result<String, Error> {
+        let failed = |message: String| Error::Fetch { day, message };
+
+        let session = self.session.as_ref().ok_or_else(|| {
+            failed("set AOC_SESSION to download inputs, or give an input path".to_string())
+        })?;
+        let url = format!("https://adventofcode.com/{}/day/{day}/input", Self::YEAR);
+
+        ureq::get(&url)
+            .header("Cookie", &format!("session={session}"))
+            .header("User-Agent", "github.com/whostolemyhat/aoc2025")
+            .call()
+            .and_then(|mut response| response.body_mut().read_to_string())
+            .map_err(|e| failed(e.to_string()))
+    }
+}
+
+/// Serves `dayNN.txt` out of a directory, a stand-in for the site in tests
+pub struct DirFetcher {
+    dir: PathBuf,
+}
+
+impl DirFetcher {
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self { dir: dir.into() }
+    }
+}
+
+impl Fetcher for DirFetcher {
+    fn fetch(&self, day: u8) -> Result<String, Error> {
+        read_input(self.dir.join(format!("day{day:02}.txt"))).map_err(|e| Error::Fetch {
+            day,
+            message: e.to_string(),
+        })
+    }
+}
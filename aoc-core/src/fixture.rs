@@ -0,0 +1,57 @@
+//! A tiny day for testing the plumbing without a real puzzle
+
+use crate::{ParseError, Solution, Streamer, Streaming, stream};
+
+/// adds up one number per line, but has to be given at least one
+pub struct Sum;
+
+impl Solution for Sum {
+    const DAY: u8 = 0;
+
+    type Input<'a> = Vec<u64>;
+    type Part1 = u64;
+    type Part2 = usize;
+
+    const STREAM: Option<Streamer> = Some(stream::<Self>);
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        let numbers: Vec<u64> = input
+            .lines()
+            .map(|line| {
+                line.parse()
+                    .map_err(|_| ParseError::at(input, line, "not a number"))
+            })
+            .collect::<Result<_, _>>()?;
+        match numbers.is_empty() {
+            true => Err(ParseError::at(input, input, "no numbers")),
+            false => Ok(numbers),
+        }
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Self::Part1 {
+        input.iter().sum()
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Self::Part2 {
+        input.len()
+    }
+}
+
+impl Streaming for Sum {
+    type State = (u64, usize);
+
+    fn feed((sum, count): &mut Self::State, line: &str) -> Result<(), ParseError> {
+        *sum += line
+            .parse::<u64>()
+            .map_err(|_| ParseError::at(line, line, "not a number"))?;
+        *count += 1;
+        Ok(())
+    }
+
+    fn finish(state: Self::State) -> Result<(Self::Part1, Self::Part2), ParseError> {
+        match state {
+            (_, 0) => Err(ParseError::at("", "", "no numbers")),
+            state => Ok(state),
+        }
+    }
+}
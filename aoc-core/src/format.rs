@@ -0,0 +1,178 @@
+use std::str::FromStr;
+
+use serde::Serialize;
+
+use crate::{Answer, Error, Part, answers::Status};
+
+/// How runners print their results
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    /// for people
+    #[default]
+    Text,
+    /// one JSON object per line, for scripts
+    Json,
+}
+
+impl FromStr for Format {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            _ => Err(Error::Usage(format!(
+                "format must be text or json, got '{s}'"
+            ))),
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct AnswerRecord<'a> {
+    day: u8,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    example: Option<&'a str>,
+    part: u8,
+    answer: &'a str,
+    /// null when the part wasn't timed on its own
+    duration_ns: Option<u128>,
+    /// null when there was nothing to check against
+    status: Option<&'static str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    expected: Option<&'a str>,
+}
+
+#[derive(Serialize)]
+struct ErrorRecord<'a> {
+    day: u8,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    example: Option<&'a str>,
+    error: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    line: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    column: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    len: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    text: Option<&'a str>,
+}
+
+/// `{"day":1,"part":1,"answer":"3","duration_ns":1200,"status":"PASS"}`
+pub fn answer_json(answer: &Answer, status: Option<&Status>, example: Option<&str>) -> String {
+    let record = AnswerRecord {
+        day: answer.day,
+        example,
+        part: match answer.part {
+            Part::One => 1,
+            Part::Two => 2,
+        },
+        answer: &answer.value,
+        duration_ns: answer.duration.map(|duration| duration.as_nanos()),
+        status: status.map(|status| match status {
+            Status::Pass => "PASS",
+            Status::Fail { .. } => "FAIL",
+            Status::Unknown => "UNKNOWN",
+        }),
+        expected: match status {
+            Some(Status::Fail { expected }) => Some(expected),
+            _ => None,
+        },
+    };
+
+    serde_json::to_string(&record).expect("answers always serialise")
+}
+
+/// `{"day":1,"error":"expected L or R, got 'X'","line":3,"column":1,"len":1,"text":"X48"}`
+/// anything other than a parse error only has `day` and `error`
+pub fn error_json(day: u8, error: &Error, example: Option<&str>) -> String {
+    let record = match error {
+        Error::Parse(e) => ErrorRecord {
+            day,
+            example,
+            error: e.message.clone(),
+            line: Some(e.line),
+            column: Some(e.column),
+            len: Some(e.len),
+            text: Some(&e.text),
+        },
+        e => ErrorRecord {
+            day,
+            example,
+            error: e.to_string(),
+            line: None,
+            column: None,
+            len: None,
+            text: None,
+        },
+    };
+
+    serde_json::to_string(&record).expect("errors always serialise")
+}
+
+#[cfg(test)]
+mod test {
+    use std::time::Duration;
+
+    use crate::{
+        Answer, Error, ParseError, Part,
+        answers::Status,
+        format::{Format, answer_json, error_json},
+    };
+
+    #[test]
+    fn it_should_write_answers() {
+        let answer = Answer {
+            day: 1,
+            part: Part::Two,
+            value: "6".to_string(),
+            duration: Some(Duration::from_nanos(1500)),
+        };
+
+        assert_eq!(
+            answer_json(&answer, None, None),
+            r#"{"day":1,"part":2,"answer":"6","duration_ns":1500,"status":null}"#
+        );
+        assert_eq!(
+            answer_json(
+                &answer,
+                Some(&Status::Fail {
+                    expected: "7".to_string()
+                }),
+                Some("example")
+            ),
+            r#"{"day":1,"example":"example","part":2,"answer":"6","duration_ns":1500,"status":"FAIL","expected":"7"}"#
+        );
+
+        let streamed = Answer {
+            duration: None,
+            ..answer
+        };
+        assert_eq!(
+            answer_json(&streamed, None, None),
+            r#"{"day":1,"part":2,"answer":"6","duration_ns":null,"status":null}"#
+        );
+    }
+
+    #[test]
+    fn it_should_write_diagnostics() {
+        let input = "L68\nX30";
+        let err = Error::Parse(ParseError::at(input, &input[4..5], "expected L or R"));
+
+        assert_eq!(
+            error_json(1, &err, None),
+            r#"{"day":1,"error":"expected L or R","line":2,"column":1,"len":1,"text":"X30"}"#
+        );
+        assert_eq!(
+            error_json(2, &Error::Usage("bad".to_string()), None),
+            r#"{"day":2,"error":"usage: bad"}"#
+        );
+    }
+
+    #[test]
+    fn it_should_parse_formats() {
+        assert_eq!("json".parse::<Format>().unwrap(), Format::Json);
+        assert!("yaml".parse::<Format>().is_err());
+    }
+}
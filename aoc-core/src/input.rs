@@ -0,0 +1,221 @@
+use std::{
+    fs::{self, File, read_to_string},
+    io::{self, BufRead, BufReader, Cursor, Read},
+    path::{Path, PathBuf},
+};
+
+use sha2::{Digest, Sha256};
+
+use crate::{Error, Fetcher};
+
+/// where downloaded inputs are kept, relative to the workspace root
+pub const DEFAULT_INPUT_DIR: &str = "inputs";
+
+/// an input path of `-` reads from stdin instead
+pub const STDIN: &str = "-";
+
+/// read a whole puzzle input into memory
+pub fn read_input(path: impl AsRef<Path>) -> Result<String, Error> {
+    let path = path.as_ref();
+    read_to_string(path).map_err(|source| Error::Io {
+        path: path.to_path_buf(),
+        source,
+    })
+}
+
+/// walk up from `start` to the Cargo.toml with the `[workspace]` in it
+pub fn find_workspace(start: &Path) -> Option<PathBuf> {
+    start
+        .ancestors()
+        .find(|dir| {
+            read_input(dir.join("Cargo.toml"))
+                .is_ok_and(|manifest| manifest.contains("[workspace]"))
+        })
+        .map(Path::to_path_buf)
+}
+
+/// use `path` if there is one, otherwise the cached input for `day`
+/// (downloading it the first time)
+pub fn load_input(day: u8, path: Option<&Path>) -> Result<String, Error> {
+    match path {
+        Some(path) if path == Path::new(STDIN) => {
+            let mut input = String::new();
+            io::stdin()
+                .read_to_string(&mut input)
+                .map_err(|source| Error::Io {
+                    path: PathBuf::from("stdin"),
+                    source,
+                })?;
+            Ok(input)
+        }
+        Some(path) => read_input(path),
+        #[cfg(feature = "http")]
+        None => InputCache::new(DEFAULT_INPUT_DIR, crate::HttpFetcher::from_env()).get(day),
+        #[cfg(not(feature = "http"))]
+        None => {
+            InputCache::new(DEFAULT_INPUT_DIR, crate::DirFetcher::new(DEFAULT_INPUT_DIR)).get(day)
+        }
+    }
+}
+
+/// like [`load_input`], but only reads as much as the caller asks for
+/// (cached inputs are still read in one go)
+pub fn open_input(day: u8, path: Option<&Path>) -> Result<Box<dyn BufRead>, Error> {
+    match path {
+        Some(path) if path == Path::new(STDIN) => Ok(Box::new(io::stdin().lock())),
+        Some(path) => {
+            let file = File::open(path).map_err(|source| Error::Io {
+                path: path.to_path_buf(),
+                source,
+            })?;
+            Ok(Box::new(BufReader::new(file)))
+        }
+        None => Ok(Box::new(Cursor::new(load_input(day, None)?))),
+    }
+}
+
+/// Inputs kept on disk as `dayNN.txt`, with a `dayNN.txt.sha256` alongside
+///
+/// Anything missing is fetched once; after that the file on disk is used and checked
+/// against its checksum, so a cached input is never downloaded again.
+pub struct InputCache<F> {
+    dir: PathBuf,
+    fetcher: F,
+}
+
+impl<F: Fetcher> InputCache<F> {
+    pub fn new(dir: impl Into<PathBuf>, fetcher: F) -> Self {
+        Self {
+            dir: dir.into(),
+            fetcher,
+        }
+    }
+
+    pub fn path(&self, day: u8) -> PathBuf {
+        self.dir.join(format!("day{day:02}.txt"))
+    }
+
+    fn checksum_path(&self, day: u8) -> PathBuf {
+        self.dir.join(format!("day{day:02}.txt.sha256"))
+    }
+
+    pub fn get(&self, day: u8) -> Result<String, Error> {
+        let path = self.path(day);
+        let checksum_path = self.checksum_path(day);
+
+        if path.exists() {
+            let input = read_input(&path)?;
+
+            // inputs dropped in by hand won't have a checksum yet, so start tracking them
+            if !checksum_path.exists() {
+                self.write(&checksum_path, &checksum(&input))?;
+            } else if read_input(&checksum_path)?.trim() != checksum(&input) {
+                return Err(Error::Checksum { path });
+            }
+
+            return Ok(input);
+        }
+
+        let input = self.fetcher.fetch(day)?;
+        fs::create_dir_all(&self.dir).map_err(|source| Error::Io {
+            path: self.dir.clone(),
+            source,
+        })?;
+        self.write(&path, &input)?;
+        self.write(&checksum_path, &checksum(&input))?;
+
+        Ok(input)
+    }
+
+    fn write(&self, path: &Path, contents: &str) -> Result<(), Error> {
+        fs::write(path, contents).map_err(|source| Error::Io {
+            path: path.to_path_buf(),
+            source,
+        })
+    }
+}
+
+/// sha256 as lowercase hex
+fn checksum(input: &str) -> String {
+    Sha256::digest(input.as_bytes())
+        .iter()
+        .map(|b| format!("{b:02x}"))
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use std::{cell::Cell, env::temp_dir, fs, path::PathBuf, process};
+
+    use crate::{DirFetcher, Error, Fetcher, InputCache, read_input};
+
+    /// a `DirFetcher` that remembers how often it was asked
+    struct CountingFetcher {
+        inner: DirFetcher,
+        calls: Cell<usize>,
+    }
+
+    impl Fetcher for CountingFetcher {
+        fn fetch(&self, day: u8) -> Result<String, Error> {
+            self.calls.set(self.calls.get() + 1);
+            self.inner.fetch(day)
+        }
+    }
+
+    fn scratch(name: &str) -> PathBuf {
+        let dir = temp_dir().join(format!("aoc-core-{name}-{}", process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    fn it_should_report_missing_files() {
+        let err = read_input("does/not/exist.txt").unwrap_err();
+        assert!(matches!(err, Error::Io { .. }));
+        assert!(
+            err.to_string()
+                .starts_with("failed to read does/not/exist.txt")
+        );
+    }
+
+    #[test]
+    fn it_should_only_fetch_once() {
+        let server = scratch("server");
+        let cache_dir = scratch("cache");
+        fs::write(server.join("day01.txt"), "L68\nL30\n").unwrap();
+
+        let fetcher = CountingFetcher {
+            inner: DirFetcher::new(&server),
+            calls: Cell::new(0),
+        };
+        let cache = InputCache::new(&cache_dir, fetcher);
+
+        assert_eq!(cache.get(1).unwrap(), "L68\nL30\n");
+        assert_eq!(cache.get(1).unwrap(), "L68\nL30\n");
+        assert_eq!(cache.fetcher.calls.get(), 1);
+        assert!(cache_dir.join("day01.txt.sha256").exists());
+
+        // nothing on the server for day 2
+        assert!(matches!(cache.get(2), Err(Error::Fetch { day: 2, .. })));
+
+        fs::remove_dir_all(&server).unwrap();
+        fs::remove_dir_all(&cache_dir).unwrap();
+    }
+
+    #[test]
+    fn it_should_notice_changed_inputs() {
+        let cache_dir = scratch("checksum");
+        let cache = InputCache::new(&cache_dir, DirFetcher::new(&cache_dir));
+
+        // inputs put there by hand are trusted and start being tracked
+        fs::write(cache.path(3), "987654321111111\n").unwrap();
+        assert!(cache.get(3).is_ok());
+
+        fs::write(cache.path(3), "987654321111112\n").unwrap();
+        let err = cache.get(3);
+        fs::remove_dir_all(&cache_dir).unwrap();
+
+        assert!(matches!(err, Err(Error::Checksum { .. })));
+    }
+}
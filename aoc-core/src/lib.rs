@@ -0,0 +1,37 @@
+//! Shared plumbing for the dayNN crates
+//!
+//! Each day implements [`Solution`] and hands itself to [`run`], which takes care of
+//! finding the input (downloading it into `inputs/` if needed), printing the answers
+//! and checking them against `answers.toml`.
+
+pub mod answers;
+mod error;
+pub mod examples;
+mod fetch;
+#[cfg(test)]
+mod fixture;
+mod format;
+mod input;
+pub mod monotonic;
+mod options;
+mod parse_error;
+pub mod period;
+mod run;
+mod solution;
+mod stream;
+
+pub use answers::Answers;
+pub use error::Error;
+pub use examples::{DEFAULT_EXAMPLES_DIR, Example, examples_dir, load_examples};
+#[cfg(feature = "http")]
+pub use fetch::HttpFetcher;
+pub use fetch::{DirFetcher, Fetcher};
+pub use format::{Format, answer_json, error_json};
+pub use input::{
+    DEFAULT_INPUT_DIR, InputCache, STDIN, find_workspace, load_input, open_input, read_input,
+};
+pub use options::{Options, flag_value};
+pub use parse_error::ParseError;
+pub use run::{Case, Checked, Mode, ModeInput, run, run_cases, run_with};
+pub use solution::{Answer, Part, Report, Runner, Solution, solve};
+pub use stream::{Streamer, Streaming, stream};
@@ -0,0 +1,303 @@
+//! Stacks and queues that stay sorted by dropping whatever's out of order as new items arrive
+//!
+//! Handy for "biggest number you can make by removing k digits" and sliding window max/min.
+
+use std::{cmp::Ordering, collections::VecDeque};
+
+/// Which way items run from the oldest to the newest
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Order {
+    /// never going down, anything bigger than a new item is dropped
+    Increasing,
+    /// never going up, anything smaller than a new item is dropped
+    Decreasing,
+}
+
+impl Order {
+    /// whether `old` has to go before `new` can be added
+    fn breaks(self, old: Ordering) -> bool {
+        match self {
+            Order::Increasing => old == Ordering::Greater,
+            Order::Decreasing => old == Ordering::Less,
+        }
+    }
+}
+
+/// A stack kept in [`Order`], optionally with a limit on how many items it can drop
+///
+/// Once the budget runs out new items just go on top, so keeping the biggest digits of a
+/// number in order is a decreasing stack allowed to drop as many digits as there are spare:
+///
+/// ```
+/// use aoc_core::monotonic::{MonotonicStack, Order};
+///
+/// let mut stack = MonotonicStack::new(Order::Decreasing).with_budget(3);
+/// stack.extend("234234234234278".bytes().map(|b| b - b'0'));
+/// assert_eq!(stack.as_slice()[..12], [4, 3, 4, 2, 3, 4, 2, 3, 4, 2, 7, 8]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct MonotonicStack<T, F> {
+    order: Order,
+    compare: F,
+    items: Vec<T>,
+    /// how many more items can be dropped, `None` for as many as it takes
+    budget: Option<usize>,
+}
+
+impl<T: Ord> MonotonicStack<T, fn(&T, &T) -> Ordering> {
+    pub fn new(order: Order) -> Self {
+        Self::by(order, T::cmp)
+    }
+}
+
+impl<T, F: Fn(&T, &T) -> Ordering> MonotonicStack<T, F> {
+    /// order items with `compare` instead, eg. `f64::total_cmp`
+    pub fn by(order: Order, compare: F) -> Self {
+        Self {
+            order,
+            compare,
+            items: vec![],
+            budget: None,
+        }
+    }
+
+    /// drop at most `budget` items in total
+    pub fn with_budget(mut self, budget: usize) -> Self {
+        self.budget = Some(budget);
+        self
+    }
+
+    /// add `item`, dropping whatever it breaks the order with (while the budget lasts)
+    pub fn push(&mut self, item: T) {
+        while self.budget != Some(0)
+            && self
+                .items
+                .last()
+                .is_some_and(|last| self.order.breaks((self.compare)(last, &item)))
+        {
+            self.items.pop();
+            self.budget = self.budget.map(|budget| budget - 1);
+        }
+        self.items.push(item);
+    }
+
+    /// take the newest item off, which doesn't use up the budget
+    pub fn pop(&mut self) -> Option<T> {
+        self.items.pop()
+    }
+
+    pub fn last(&self) -> Option<&T> {
+        self.items.last()
+    }
+
+    /// how many more items can be dropped, `None` when there's no limit
+    pub fn budget(&self) -> Option<usize> {
+        self.budget
+    }
+
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    /// oldest first
+    pub fn as_slice(&self) -> &[T] {
+        &self.items
+    }
+
+    pub fn into_vec(self) -> Vec<T> {
+        self.items
+    }
+}
+
+impl<T> MonotonicStack<T, Box<dyn Fn(&T, &T) -> Ordering>> {
+    /// order items by what `key` picks out of them
+    pub fn by_key<K: Ord>(order: Order, key: impl Fn(&T) -> K + 'static) -> Self {
+        Self::by(order, Box::new(move |a, b| key(a).cmp(&key(b))))
+    }
+}
+
+impl<T, F: Fn(&T, &T) -> Ordering> Extend<T> for MonotonicStack<T, F> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for item in iter {
+            self.push(item);
+        }
+    }
+}
+
+/// A queue kept in [`Order`] whose front is the smallest (increasing) or biggest
+/// (decreasing) item still in it
+///
+/// Items are numbered as they're pushed so the old ones can be let go with
+/// [`MonotonicQueue::expire`], which is all a sliding window needs.
+#[derive(Debug, Clone)]
+pub struct MonotonicQueue<T, F> {
+    order: Order,
+    compare: F,
+    /// with the number each item was pushed as
+    items: VecDeque<(usize, T)>,
+    pushed: usize,
+}
+
+impl<T: Ord> MonotonicQueue<T, fn(&T, &T) -> Ordering> {
+    pub fn new(order: Order) -> Self {
+        Self::by(order, T::cmp)
+    }
+}
+
+impl<T, F: Fn(&T, &T) -> Ordering> MonotonicQueue<T, F> {
+    pub fn by(order: Order, compare: F) -> Self {
+        Self {
+            order,
+            compare,
+            items: VecDeque::new(),
+            pushed: 0,
+        }
+    }
+
+    /// add `item` at the back, returning the number it was pushed as (counting from 0)
+    pub fn push(&mut self, item: T) -> usize {
+        while self
+            .items
+            .back()
+            .is_some_and(|(_, last)| self.order.breaks((self.compare)(last, &item)))
+        {
+            self.items.pop_back();
+        }
+
+        let index = self.pushed;
+        self.items.push_back((index, item));
+        self.pushed += 1;
+        index
+    }
+
+    /// forget everything pushed before `index`
+    pub fn expire(&mut self, index: usize) {
+        while self.items.front().is_some_and(|(i, _)| *i < index) {
+            self.items.pop_front();
+        }
+    }
+
+    /// the smallest or biggest item, the oldest one if there's a tie
+    pub fn front(&self) -> Option<&T> {
+        self.items.front().map(|(_, item)| item)
+    }
+
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+}
+
+impl<T> MonotonicQueue<T, Box<dyn Fn(&T, &T) -> Ordering>> {
+    /// order items by what `key` picks out of them
+    pub fn by_key<K: Ord>(order: Order, key: impl Fn(&T) -> K + 'static) -> Self {
+        Self::by(order, Box::new(move |a, b| key(a).cmp(&key(b))))
+    }
+}
+
+/// the biggest item in every `window` long run of `items`, or nothing if there aren't that many
+///
+/// ```
+/// use aoc_core::monotonic::sliding_max;
+///
+/// assert_eq!(sliding_max(&[1, 3, 2, 5, 4], 2), [&3, &3, &5, &5]);
+/// ```
+pub fn sliding_max<T: Ord>(items: &[T], window: usize) -> Vec<&T> {
+    sliding(items, window, Order::Decreasing)
+}
+
+/// the smallest item in every `window` long run of `items`, or nothing if there aren't that many
+pub fn sliding_min<T: Ord>(items: &[T], window: usize) -> Vec<&T> {
+    sliding(items, window, Order::Increasing)
+}
+
+fn sliding<T: Ord>(items: &[T], window: usize, order: Order) -> Vec<&T> {
+    assert!(window > 0, "windows need at least one item");
+
+    let mut queue = MonotonicQueue::by(order, |a: &&T, b: &&T| a.cmp(b));
+    let mut fronts = Vec::with_capacity(items.len().saturating_sub(window - 1));
+    for item in items {
+        let index = queue.push(item);
+        if index + 1 >= window {
+            queue.expire(index + 1 - window);
+            fronts.push(*queue.front().expect("just pushed an item"));
+        }
+    }
+    fronts
+}
+
+#[cfg(test)]
+mod test {
+    use crate::monotonic::{MonotonicQueue, MonotonicStack, Order, sliding_max, sliding_min};
+
+    #[test]
+    fn stack_should_keep_numbers_in_order() {
+        let mut stack = MonotonicStack::new(Order::Increasing).with_budget(3);
+        stack.extend([1, 9, 5]);
+        assert_eq!(stack.as_slice(), [1, 5]);
+        assert_eq!(stack.budget(), Some(2));
+
+        let mut stack = MonotonicStack::by(Order::Increasing, f32::total_cmp).with_budget(5);
+        stack.extend([9.0, 10.0, 9.1, 12.0, 15.0]);
+        assert_eq!(stack.into_vec(), [9.0, 9.1, 12.0, 15.0]);
+    }
+
+    #[test]
+    fn stack_should_stop_dropping_when_out_of_budget() {
+        let mut stack = MonotonicStack::new(Order::Decreasing).with_budget(1);
+        stack.extend([1, 2, 3]);
+        assert_eq!(stack.as_slice(), [2, 3]);
+        assert_eq!(stack.budget(), Some(0));
+
+        let mut unlimited = MonotonicStack::new(Order::Decreasing);
+        unlimited.extend([1, 2, 3, 3, 1]);
+        assert_eq!(unlimited.as_slice(), [3, 3, 1]);
+        assert_eq!(unlimited.budget(), None);
+    }
+
+    #[test]
+    fn stack_should_order_by_key() {
+        let mut stack = MonotonicStack::by_key(Order::Decreasing, |word: &&str| word.len());
+        stack.extend(["a", "abc", "ab", "xyz", "b"]);
+        assert_eq!(stack.as_slice(), ["abc", "xyz", "b"]);
+    }
+
+    #[test]
+    fn queue_should_expire_old_items() {
+        let mut queue = MonotonicQueue::new(Order::Decreasing);
+        assert_eq!(queue.push(5), 0);
+        queue.push(3);
+        queue.push(4);
+        assert_eq!(queue.front(), Some(&5));
+        assert_eq!(queue.len(), 2);
+
+        queue.expire(1);
+        assert_eq!(queue.front(), Some(&4));
+        queue.expire(3);
+        assert!(queue.is_empty());
+    }
+
+    #[test]
+    fn it_should_slide_windows() {
+        let items = [4, 2, 12, 11, -5, 3, 3, 0, 8];
+        for window in 1..=items.len() + 1 {
+            let maxes: Vec<&i32> = items
+                .windows(window)
+                .map(|w| w.iter().max().unwrap())
+                .collect();
+            let mins: Vec<&i32> = items
+                .windows(window)
+                .map(|w| w.iter().min().unwrap())
+                .collect();
+            assert_eq!(sliding_max(&items, window), maxes, "window {window}");
+            assert_eq!(sliding_min(&items, window), mins, "window {window}");
+        }
+    }
+}
@@ -0,0 +1,80 @@
+use std::path::PathBuf;
+
+use crate::{Answers, Error, Format};
+
+/// Flags understood by every runner, both the dayNN binaries and `aoc run`
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Options {
+    /// expected answers to check against, defaults to `answers.toml` if it exists
+    pub answers: Option<PathBuf>,
+    /// report how long parsing and each part took
+    pub time: bool,
+    /// run the examples in `examples/dayNN/` instead of the real input
+    pub examples: bool,
+    /// read the input a line at a time, for days that can
+    pub stream: bool,
+    pub format: Format,
+}
+
+impl Options {
+    /// consume `flag` (and its value) if it's one of the shared flags
+    /// returns false so callers can handle their own flags
+    pub fn parse_flag(
+        &mut self,
+        flag: &str,
+        args: &mut impl Iterator<Item = String>,
+    ) -> Result<bool, Error> {
+        match flag {
+            "--answers" => self.answers = Some(PathBuf::from(flag_value(flag, args.next())?)),
+            "--time" => self.time = true,
+            "--examples" => self.examples = true,
+            "--stream" => self.stream = true,
+            "--format" => self.format = flag_value(flag, args.next())?.parse()?,
+            _ => return Ok(false),
+        }
+
+        Ok(true)
+    }
+
+    /// the answers file to check against, if there is one
+    ///
+    /// Examples bring their own answers, so there's nothing to load for those.
+    pub fn load_answers(&self) -> Result<Option<Answers>, Error> {
+        match self.examples {
+            true => Ok(None),
+            false => Answers::load_optional(self.answers.as_deref()),
+        }
+    }
+}
+
+pub fn flag_value(flag: &str, value: Option<String>) -> Result<String, Error> {
+    value.ok_or_else(|| Error::Usage(format!("{flag} needs a value")))
+}
+
+#[cfg(test)]
+mod test {
+    use std::path::PathBuf;
+
+    use crate::{Format, Options};
+
+    #[test]
+    fn it_should_only_take_shared_flags() {
+        let mut options = Options::default();
+        let mut args = vec!["my_answers.toml".to_string()].into_iter();
+
+        assert!(options.parse_flag("--answers", &mut args).unwrap());
+        assert_eq!(options.answers, Some(PathBuf::from("my_answers.toml")));
+        assert!(options.parse_flag("--time", &mut args).unwrap());
+        assert!(options.time);
+        assert!(options.parse_flag("--examples", &mut args).unwrap());
+        assert!(options.examples);
+        assert!(options.parse_flag("--stream", &mut args).unwrap());
+        assert!(options.stream);
+
+        let mut args = vec!["json".to_string()].into_iter();
+        assert!(options.parse_flag("--format", &mut args).unwrap());
+        assert_eq!(options.format, Format::Json);
+        assert!(!options.parse_flag("--part", &mut args).unwrap());
+        assert!(options.parse_flag("--answers", &mut args).is_err());
+    }
+}
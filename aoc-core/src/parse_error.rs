@@ -0,0 +1,139 @@
+use std::fmt;
+
+/// Something in a puzzle input that couldn't be parsed, and where it is
+///
+/// Displays as a diagnostic with the offending line and a caret under the problem.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// filled in by [`crate::solve`], 0 until then
+    pub day: u8,
+    /// 1-based
+    pub line: usize,
+    /// 1-based, in chars
+    pub column: usize,
+    /// how many chars to underline
+    pub len: usize,
+    /// the whole line the problem is on
+    pub text: String,
+    pub message: String,
+}
+
+impl ParseError {
+    /// point at `token`, which has to be a slice of `source`
+    /// (anything else is reported at the start of `source`)
+    pub fn at(source: &str, token: &str, message: impl Into<String>) -> Self {
+        let offset = offset_of(source, token);
+        let line_start = source[..offset].rfind('\n').map_or(0, |i| i + 1);
+        let line_end = source[offset..]
+            .find('\n')
+            .map_or(source.len(), |i| offset + i);
+
+        Self {
+            day: 0,
+            line: source[..offset].matches('\n').count() + 1,
+            column: source[line_start..offset].chars().count() + 1,
+            len: token.chars().count(),
+            text: source[line_start..line_end]
+                .trim_end_matches('\r')
+                .to_string(),
+            message: message.into(),
+        }
+    }
+
+    /// move an error raised while parsing `part` so it points into the whole of `source`
+    /// eg. a `FromStr` impl that only saw one line
+    pub fn within(self, source: &str, part: &str) -> Self {
+        let start = Self::at(source, part, "");
+        let line = start.line + self.line - 1;
+        let column = if self.line == 1 {
+            start.column + self.column - 1
+        } else {
+            self.column
+        };
+
+        Self {
+            line,
+            column,
+            text: source.lines().nth(line - 1).map_or(self.text, String::from),
+            ..self
+        }
+    }
+}
+
+fn offset_of(source: &str, token: &str) -> usize {
+    let offset = (token.as_ptr() as usize).wrapping_sub(source.as_ptr() as usize);
+    if offset <= source.len() { offset } else { 0 }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.day > 0 {
+            write!(f, "day {:02}, ", self.day)?;
+        }
+        writeln!(
+            f,
+            "line {}, column {}: {}",
+            self.line, self.column, self.message
+        )?;
+
+        let gutter = " ".repeat(self.line.to_string().len());
+        writeln!(f, "{gutter} |")?;
+        writeln!(f, "{} | {}", self.line, self.text)?;
+        write!(
+            f,
+            "{gutter} | {}{}",
+            " ".repeat(self.column - 1),
+            "^".repeat(self.len.max(1))
+        )
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+#[cfg(test)]
+mod test {
+    use crate::ParseError;
+
+    #[test]
+    fn it_should_point_at_the_token() {
+        let input = "L68\nX30\nR48";
+        let err = ParseError::at(input, &input[4..5], "expected L or R");
+
+        assert_eq!((err.line, err.column, err.len), (2, 1, 1));
+        assert_eq!(err.text, "X30");
+        assert_eq!(
+            err.to_string(),
+            "line 2, column 1: expected L or R
+  |
+2 | X30
+  | ^"
+        );
+    }
+
+    #[test]
+    fn it_should_move_errors_into_the_whole_input() {
+        let input = "1-2,3-x";
+        let part = &input[4..];
+        let err = ParseError::at(part, &part[2..], "not a number").within(input, part);
+
+        assert_eq!((err.line, err.column), (1, 7));
+        assert_eq!(err.text, input);
+
+        let input = "3-5\n10-x";
+        let line = input.lines().nth(1).unwrap();
+        let err = ParseError::at(line, &line[3..], "not a number").within(input, line);
+        assert_eq!((err.line, err.column), (2, 4));
+        assert_eq!(err.text, "10-x");
+    }
+
+    #[test]
+    fn it_should_include_the_day() {
+        let mut err = ParseError::at("", "", "empty input");
+        err.day = 5;
+
+        assert!(
+            err.to_string()
+                .starts_with("day 05, line 1, column 1: empty input")
+        );
+    }
+}
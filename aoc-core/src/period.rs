@@ -0,0 +1,146 @@
+//! Repeating patterns in strings, or any other slices
+//!
+//! Everything is worked out from the KMP failure function, the longest proper prefix of each
+//! prefix that's also a suffix of it. A slice of length n with a border of length b repeats
+//! every n - b.
+
+/// Works out periods while reusing one table between slices, so checking lots of them in a
+/// loop doesn't allocate once the table is big enough
+///
+/// ```
+/// use aoc_core::period::Periods;
+///
+/// let mut periods = Periods::default();
+/// assert_eq!(periods.smallest(b"abcabcab"), 3);
+/// assert_eq!(periods.all(b"abaab").collect::<Vec<_>>(), [3, 5]);
+/// assert!(periods.is_power(b"121212", 3));
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct Periods {
+    failure: Vec<usize>,
+}
+
+impl Periods {
+    /// the shortest p where every item matches the one p further on, the length when nothing
+    /// shorter works (and 0 for an empty slice)
+    pub fn smallest<T: PartialEq>(&mut self, s: &[T]) -> usize {
+        s.len() - self.border(s)
+    }
+
+    /// every period, shortest first and ending with the length itself
+    pub fn all<T: PartialEq>(&mut self, s: &[T]) -> impl Iterator<Item = usize> + '_ {
+        let len = s.len();
+        // every border is a border of the longest one, so they're a chain down to 0
+        let mut border = Some(self.border(s)).filter(|_| len > 0);
+        let failure = &self.failure;
+
+        std::iter::from_fn(move || {
+            let current = border?;
+            border = (current > 0).then(|| failure[current - 1]);
+            Some(len - current)
+        })
+    }
+
+    /// whether `s` is one block repeated exactly `k` times
+    pub fn is_power<T: PartialEq>(&mut self, s: &[T], k: usize) -> bool {
+        if k == 0 || !s.len().is_multiple_of(k) {
+            return false;
+        }
+
+        // any shorter period that divides the length is a multiple of the smallest one
+        let block = s.len() / k;
+        block == s.len() || block.is_multiple_of(self.smallest(s))
+    }
+
+    /// fill in the failure table for `s` and return its longest border
+    fn border<T: PartialEq>(&mut self, s: &[T]) -> usize {
+        self.failure.clear();
+        self.failure.resize(s.len(), 0);
+
+        for i in 1..s.len() {
+            let mut k = self.failure[i - 1];
+            while k > 0 && s[i] != s[k] {
+                k = self.failure[k - 1];
+            }
+            if s[i] == s[k] {
+                k += 1;
+            }
+            self.failure[i] = k;
+        }
+
+        self.failure.last().copied().unwrap_or(0)
+    }
+}
+
+/// [`Periods::smallest`] for a one-off check
+pub fn smallest_period<T: PartialEq>(s: &[T]) -> usize {
+    Periods::default().smallest(s)
+}
+
+/// [`Periods::is_power`] for a one-off check
+pub fn is_power<T: PartialEq>(s: &[T], k: usize) -> bool {
+    Periods::default().is_power(s, k)
+}
+
+#[cfg(test)]
+mod test {
+    use crate::period::{Periods, is_power, smallest_period};
+
+    /// every p where the slice matches itself shifted by p
+    fn periods_by_shifting(s: &[u8]) -> Vec<usize> {
+        (1..=s.len())
+            .filter(|p| s[*p..] == s[..s.len() - p])
+            .collect()
+    }
+
+    #[test]
+    fn it_should_find_the_smallest_period() {
+        assert_eq!(smallest_period(b"1188511885"), 5);
+        assert_eq!(smallest_period(b"999"), 1);
+        assert_eq!(smallest_period(b"1021"), 3);
+        assert_eq!(smallest_period(b"7"), 1);
+        assert_eq!(smallest_period::<u8>(&[]), 0);
+        assert_eq!(smallest_period(&[3, 1, 3, 1, 3]), 2);
+    }
+
+    #[test]
+    fn it_should_find_every_period() {
+        let mut periods = Periods::default();
+        assert_eq!(periods.all(b"aaaa").collect::<Vec<_>>(), [1, 2, 3, 4]);
+        assert_eq!(periods.all(b"abcab").collect::<Vec<_>>(), [3, 5]);
+        assert_eq!(periods.all(b"").count(), 0);
+
+        for s in ["abaababaab", "1212121", "xyz", "aabaaabaa"] {
+            assert_eq!(
+                periods.all(s.as_bytes()).collect::<Vec<_>>(),
+                periods_by_shifting(s.as_bytes()),
+                "{s}"
+            );
+        }
+    }
+
+    #[test]
+    fn it_should_check_powers() {
+        assert!(is_power(b"1010", 2));
+        assert!(is_power(b"1111", 2));
+        assert!(is_power(b"1111", 4));
+        assert!(is_power(b"123", 1));
+        assert!(is_power(b"abaab", 1));
+        assert!(!is_power(b"1111", 3));
+        assert!(!is_power(b"101", 2));
+        assert!(!is_power(b"abaaba", 3));
+        assert!(!is_power(b"12", 0));
+    }
+
+    #[test]
+    fn it_should_reuse_its_table() {
+        let mut periods = Periods::default();
+        periods.smallest(&[0u8; 64]);
+        let capacity = periods.failure.capacity();
+
+        for n in 0..1000u32 {
+            periods.smallest(n.to_string().as_bytes());
+        }
+        assert_eq!(periods.failure.capacity(), capacity);
+    }
+}
@@ -0,0 +1,391 @@
+use std::{
+    env::args,
+    io::{self, BufWriter, Write},
+    path::{Path, PathBuf},
+    process::ExitCode,
+    time::Duration,
+};
+
+use crate::{
+    Answer, Answers, Error, Format, Options, ParseError, Part, Report, Runner, Solution,
+    answer_json, answers::Status, error_json, examples_dir, flag_value, load_examples, load_input,
+    open_input,
+};
+
+/// entry point for the dayNN binaries: `cargo run --bin day02 ./day02/input.txt`
+/// without a filename the input comes from `inputs/dayNN.txt`, and `-` reads stdin
+pub fn run<S: Solution + Sync>(day: &S) -> ExitCode {
+    run_with(day, &[])
+}
+
+/// A day's own way of showing its input instead of the answers, eg. day02's `--list`
+pub struct Mode<S: Solution> {
+    /// the flag that picks it
+    pub flag: &'static str,
+    /// what goes after the flag, eg. `<trace file>`, or `None` for a flag on its own
+    pub value: Option<&'static str>,
+    /// write whatever the mode shows, returning false if it found something wrong
+    pub write: for<'a> fn(&mut dyn Write, &ModeInput<'a, S>) -> Result<bool, Error>,
+}
+
+/// What a [`Mode`] gets to work with
+pub struct ModeInput<'a, S: Solution> {
+    /// the input as it was read
+    pub text: &'a str,
+    pub parsed: S::Input<'a>,
+    /// the part from `--part`, or part 2 when it isn't given since that's usually the one worth
+    /// looking into
+    pub part: Part,
+    /// whatever was after the flag, always there for modes with a [`Mode::value`]
+    pub value: Option<&'a str>,
+}
+
+/// [`run`], except each of `modes` takes over when its flag is given
+///
+/// Modes take `[input file|-] [--part <1|2>]` and find the input the same way.
+pub fn run_with<S: Solution + Sync>(day: &S, modes: &[Mode<S>]) -> ExitCode {
+    let args: Vec<String> = args().skip(1).collect();
+    let result = match modes
+        .iter()
+        .find(|mode| args.iter().any(|arg| arg == mode.flag))
+    {
+        Some(mode) => run_mode(mode, args.into_iter()),
+        None => try_run(day, args.into_iter()),
+    };
+
+    match result {
+        Ok(true) => ExitCode::SUCCESS,
+        Ok(false) => ExitCode::FAILURE,
+        // already says which day it's from
+        Err(Error::Parse(e)) => {
+            eprintln!("{e}");
+            ExitCode::FAILURE
+        }
+        Err(e) => {
+            eprintln!("day{:02}: {e}", S::DAY);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// One run of a day, on its real input or on one of its examples
+#[derive(Debug)]
+pub struct Case {
+    pub day: u8,
+    /// `None` for the real input
+    pub example: Option<String>,
+    pub result: Result<Checked, Error>,
+}
+
+/// A [`Report`] with each answer compared to what was expected
+#[derive(Debug)]
+pub struct Checked {
+    pub parse: Duration,
+    /// with `None` for answers there was nothing to check against
+    pub answers: Vec<(Answer, Option<Status>)>,
+}
+
+impl Case {
+    fn new(
+        day: u8,
+        example: Option<String>,
+        report: Result<Report, Error>,
+        answers: Option<&Answers>,
+    ) -> Self {
+        let result = report.map(|report| Checked {
+            parse: report.parse,
+            answers: report
+                .answers
+                .into_iter()
+                .map(|answer| {
+                    let status = answers.map(|answers| answers.check(&answer));
+                    (answer, status)
+                })
+                .collect(),
+        });
+
+        Self {
+            day,
+            example,
+            result,
+        }
+    }
+
+    /// false if it failed to run or got an answer wrong
+    pub fn passed(&self) -> bool {
+        self.result.as_ref().is_ok_and(|checked| {
+            checked
+                .answers
+                .iter()
+                .all(|(_, status)| !matches!(status, Some(Status::Fail { .. })))
+        })
+    }
+
+    /// a `--format json` record per answer, or one for the error
+    pub fn json(&self) -> Vec<String> {
+        let example = self.example.as_deref();
+        match &self.result {
+            Ok(checked) => checked
+                .answers
+                .iter()
+                .map(|(answer, status)| answer_json(answer, status.as_ref(), example))
+                .collect(),
+            Err(e) => vec![error_json(self.day, e, example)],
+        }
+    }
+}
+
+/// run a day on `input`, or on each of its examples with `--examples`
+///
+/// Days that can stream do when `--stream` is given, the rest just read the whole input as
+/// usual. Answers are checked against `answers`, or against each example's own. Having no
+/// examples isn't an error here, so runners can say so however suits them.
+pub fn run_cases(
+    runner: &dyn Runner,
+    input: Option<&Path>,
+    parts: &[Part],
+    options: &Options,
+    answers: Option<&Answers>,
+) -> Result<Vec<Case>, Error> {
+    let day = runner.day();
+    let streamer = runner.streamer().filter(|_| options.stream);
+    let solve = |input: &str| match streamer {
+        Some(stream) => stream(&mut input.as_bytes(), parts),
+        None => Ok(runner.run(input, parts)?),
+    };
+
+    if !options.examples {
+        let report = match streamer {
+            Some(stream) => {
+                open_input(day, input).and_then(|mut reader| stream(&mut reader, parts))
+            }
+            None => load_input(day, input).and_then(|input| solve(&input)),
+        };
+        return Ok(vec![Case::new(day, None, report, answers)]);
+    }
+
+    if input.is_some() {
+        return Err(Error::Usage(
+            "--examples uses examples/dayNN/*.txt, not an input file".to_string(),
+        ));
+    }
+
+    Ok(load_examples(examples_dir(), day)?
+        .into_iter()
+        .map(|example| {
+            let answers = example.answers(day);
+            Case::new(
+                day,
+                Some(example.name),
+                solve(&example.input),
+                Some(&answers),
+            )
+        })
+        .collect())
+}
+
+fn run_mode<S: Solution>(
+    mode: &Mode<S>,
+    mut args: impl Iterator<Item = String>,
+) -> Result<bool, Error> {
+    let usage = || {
+        Error::Usage(format!(
+            "day{:02} {}{} [input file|-] [--part <1|2>]",
+            S::DAY,
+            mode.flag,
+            mode.value
+                .map(|value| format!(" {value}"))
+                .unwrap_or_default()
+        ))
+    };
+
+    let mut filename = None;
+    let mut part = None;
+    let mut value = None;
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--part" => part = Some(flag_value(&arg, args.next())?.parse()?),
+            flag if flag == mode.flag => {
+                if mode.value.is_some() {
+                    value = Some(args.next().ok_or_else(usage)?);
+                }
+            }
+            _ if arg.starts_with("--") || filename.is_some() => return Err(usage()),
+            _ => filename = Some(PathBuf::from(arg)),
+        }
+    }
+
+    let text = load_input(S::DAY, filename.as_deref())?;
+    let input = ModeInput {
+        text: &text,
+        parsed: S::parse(&text).map_err(|e| ParseError { day: S::DAY, ..e })?,
+        part: part.unwrap_or(Part::Two),
+        value: value.as_deref(),
+    };
+
+    let mut out = BufWriter::new(io::stdout().lock());
+    let ok = (mode.write)(&mut out, &input)?;
+    out.flush().map_err(Error::stdout)?;
+    Ok(ok)
+}
+
+/// returns false if any answer didn't match the answers file
+fn try_run(runner: &dyn Runner, mut args: impl Iterator<Item = String>) -> Result<bool, Error> {
+    let usage = || {
+        Error::Usage(format!(
+            "day{:02} [input file|-] [--answers <path>] [--time] [--examples] [--stream] [--format <text|json>]",
+            runner.day()
+        ))
+    };
+
+    let mut filename = None;
+    let mut options = Options::default();
+
+    while let Some(arg) = args.next() {
+        if options.parse_flag(&arg, &mut args)? {
+            continue;
+        }
+        if arg.starts_with("--") || filename.is_some() {
+            return Err(usage());
+        }
+        filename = Some(PathBuf::from(arg));
+    }
+
+    match run_day(runner, filename, &options) {
+        // everything goes to stdout as a record so scripts see why there are no answers
+        Err(e) if options.format == Format::Json => {
+            println!("{}", error_json(runner.day(), &e, None));
+            Ok(false)
+        }
+        result => result,
+    }
+}
+
+fn run_day(
+    runner: &dyn Runner,
+    filename: Option<PathBuf>,
+    options: &Options,
+) -> Result<bool, Error> {
+    let answers = options.load_answers()?;
+    let cases = run_cases(
+        runner,
+        filename.as_deref(),
+        &Part::ALL,
+        options,
+        answers.as_ref(),
+    )?;
+    if cases.is_empty() {
+        return Err(Error::Usage(format!(
+            "no examples in {}",
+            examples_dir()
+                .join(format!("day{:02}", runner.day()))
+                .display()
+        )));
+    }
+
+    let mut passed = true;
+    for case in cases {
+        passed &= case.passed();
+
+        if options.format == Format::Json {
+            for record in case.json() {
+                println!("{record}");
+            }
+            continue;
+        }
+
+        if let Some(example) = &case.example {
+            println!("{example}:");
+        }
+        match case.result {
+            Ok(checked) => print_checked(checked, options),
+            Err(e) if case.example.is_some() => eprintln!("{e}"),
+            // the real input failing is the whole run failing
+            Err(e) => return Err(e),
+        }
+    }
+    Ok(passed)
+}
+
+fn print_checked(checked: Checked, options: &Options) {
+    if options.time {
+        println!("parse: {:.2?}", checked.parse);
+    }
+
+    for (answer, status) in checked.answers {
+        let mut line = answer.to_string();
+        if let Some(status) = status {
+            line += &format!(" {status}");
+        }
+        if let Some(duration) = answer.duration.filter(|_| options.time) {
+            line += &format!(" ({duration:.2?})");
+        }
+
+        println!("{line}");
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::{env::temp_dir, fs, process};
+
+    use crate::{Answers, Options, Part, answers::Status, fixture::Sum, run_cases};
+
+    #[test]
+    fn it_should_check_answers_whether_streaming_or_not() {
+        let path = temp_dir().join(format!("aoc-core-run-cases-{}.txt", process::id()));
+        fs::write(&path, "1\n2\n").unwrap();
+
+        let mut answers = Answers::default();
+        answers.insert(0, Part::One, "3");
+        answers.insert(0, Part::Two, "3");
+
+        for stream in [false, true] {
+            let options = Options {
+                stream,
+                ..Options::default()
+            };
+            let [case] = run_cases(&Sum, Some(&path), &Part::ALL, &options, Some(&answers))
+                .unwrap()
+                .try_into()
+                .expect("just the real input");
+
+            assert!(case.example.is_none());
+            assert!(!case.passed());
+            let statuses: Vec<_> = case
+                .result
+                .unwrap()
+                .answers
+                .into_iter()
+                .map(|(_, status)| status)
+                .collect();
+            assert_eq!(
+                statuses,
+                [
+                    Some(Status::Pass),
+                    Some(Status::Fail {
+                        expected: "3".to_string()
+                    })
+                ]
+            );
+        }
+        fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn it_should_fail_cases_that_dont_run() {
+        let path = temp_dir().join(format!("aoc-core-run-cases-empty-{}.txt", process::id()));
+        fs::write(&path, "").unwrap();
+
+        for stream in [false, true] {
+            let options = Options {
+                stream,
+                ..Options::default()
+            };
+            let cases = run_cases(&Sum, Some(&path), &[Part::One], &options, None).unwrap();
+            assert!(!cases[0].passed());
+            assert!(cases[0].json()[0].contains("\"error\":\"no numbers\""));
+        }
+        fs::remove_file(&path).unwrap();
+    }
+}
@@ -0,0 +1,199 @@
+use std::{
+    fmt,
+    str::FromStr,
+    time::{Duration, Instant},
+};
+
+use crate::{Error, ParseError, Streamer};
+
+/// A single day's puzzle
+///
+/// `parse` does the work shared by both parts, then each part works from the parsed input.
+/// `Input` can borrow from the raw text so days don't have to copy lines around.
+pub trait Solution {
+    const DAY: u8;
+
+    type Input<'a>;
+    type Part1: fmt::Display;
+    type Part2: fmt::Display;
+
+    /// set for days that implement [`crate::Streaming`], so `--stream` can find it
+    const STREAM: Option<Streamer> = None;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError>;
+    fn part1(input: &Self::Input<'_>) -> Self::Part1;
+    fn part2(input: &Self::Input<'_>) -> Self::Part2;
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+}
+
+impl FromStr for Part {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "1" => Ok(Part::One),
+            "2" => Ok(Part::Two),
+            _ => Err(Error::Usage(format!("part must be 1 or 2, got '{s}'"))),
+        }
+    }
+}
+
+impl fmt::Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Part::One => write!(f, "1"),
+            Part::Two => write!(f, "2"),
+        }
+    }
+}
+
+/// An answer that has been turned into text, ready to print or compare
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Answer {
+    pub day: u8,
+    pub part: Part,
+    pub value: String,
+    /// time spent in the part itself, not including parsing, or `None` if it was worked out
+    /// alongside the parsing (see [`stream`](crate::stream))
+    pub duration: Option<Duration>,
+}
+
+/// Everything produced by one run of a day
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Report {
+    pub day: u8,
+    pub parse: Duration,
+    pub answers: Vec<Answer>,
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "part {}: {}", self.part, self.value)
+    }
+}
+
+/// parse once and run the requested parts, timing each phase
+pub fn solve<S: Solution>(input: &str, parts: &[Part]) -> Result<Report, ParseError> {
+    let start = Instant::now();
+    let parsed = S::parse(input).map_err(|e| ParseError { day: S::DAY, ..e })?;
+    let parse = start.elapsed();
+
+    let answers = parts
+        .iter()
+        .map(|part| {
+            let start = Instant::now();
+            let value = match part {
+                Part::One => S::part1(&parsed).to_string(),
+                Part::Two => S::part2(&parsed).to_string(),
+            };
+
+            Answer {
+                day: S::DAY,
+                part: *part,
+                value,
+                duration: Some(start.elapsed()),
+            }
+        })
+        .collect();
+
+    Ok(Report {
+        day: S::DAY,
+        parse,
+        answers,
+    })
+}
+
+/// Type-erased [`Solution`] so different days can live side by side in a registry
+pub trait Runner: Sync {
+    fn day(&self) -> u8;
+    fn run(&self, input: &str, parts: &[Part]) -> Result<Report, ParseError>;
+    fn streamer(&self) -> Option<Streamer>;
+}
+
+impl<S: Solution + Sync> Runner for S {
+    fn day(&self) -> u8 {
+        S::DAY
+    }
+
+    fn run(&self, input: &str, parts: &[Part]) -> Result<Report, ParseError> {
+        solve::<S>(input, parts)
+    }
+
+    fn streamer(&self) -> Option<Streamer> {
+        S::STREAM
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{ParseError, Part, Runner, Solution, solve};
+
+    struct LineCount;
+
+    impl Solution for LineCount {
+        const DAY: u8 = 0;
+
+        type Input<'a> = Vec<&'a str>;
+        type Part1 = usize;
+        type Part2 = String;
+
+        fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+            match input.lines().find(|line| line.is_empty()) {
+                Some(line) => Err(ParseError::at(input, line, "empty line")),
+                None => Ok(input.lines().collect()),
+            }
+        }
+
+        fn part1(input: &Self::Input<'_>) -> Self::Part1 {
+            input.len()
+        }
+
+        fn part2(input: &Self::Input<'_>) -> Self::Part2 {
+            input.concat()
+        }
+    }
+
+    #[test]
+    fn it_should_solve_both_parts() {
+        let report = solve::<LineCount>("ab\ncd", &Part::ALL).unwrap();
+        let [part1, part2] = report.answers.try_into().expect("two answers");
+
+        assert_eq!(report.day, 0);
+        assert_eq!((part1.day, part1.part), (0, Part::One));
+        assert_eq!(part1.value, "2");
+        assert_eq!(part2.value, "abcd");
+        assert_eq!(part2.to_string(), "part 2: abcd");
+    }
+
+    #[test]
+    fn it_should_only_run_requested_parts() {
+        let runner: &dyn Runner = &LineCount;
+        let answers = runner.run("ab\ncd", &[Part::Two]).unwrap().answers;
+
+        assert_eq!(runner.day(), 0);
+        assert_eq!(answers.len(), 1);
+        assert_eq!(answers[0].part, Part::Two);
+    }
+
+    #[test]
+    fn it_should_tag_parse_errors_with_the_day() {
+        let err = solve::<LineCount>("ab\n\ncd", &Part::ALL).unwrap_err();
+        assert_eq!((err.day, err.line), (0, 2));
+    }
+
+    #[test]
+    fn it_should_parse_parts() {
+        assert_eq!("1".parse::<Part>().unwrap(), Part::One);
+        assert_eq!("2".parse::<Part>().unwrap(), Part::Two);
+        assert!("3".parse::<Part>().is_err());
+    }
+}
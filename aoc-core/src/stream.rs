@@ -0,0 +1,110 @@
+use std::{io::BufRead, path::PathBuf, time::Instant};
+
+use crate::{Answer, Error, ParseError, Part, Report, Solution};
+
+/// [`stream`] for a particular day, see [`Solution::STREAM`]
+pub type Streamer = fn(&mut dyn BufRead, &[Part]) -> Result<Report, Error>;
+
+/// A day that can work out its answers a line at a time
+///
+/// Nothing but `State` is kept between lines, so inputs far bigger than memory can be piped
+/// through. Days opt in with `const STREAM: Option<Streamer> = Some(stream::<Self>);`.
+pub trait Streaming: Solution {
+    /// everything carried from one line to the next
+    type State: Default;
+
+    /// errors should point into `line`, [`stream`] fills in which line it was
+    fn feed(state: &mut Self::State, line: &str) -> Result<(), ParseError>;
+    /// for input that ends too soon, which is reported just after the last line
+    fn finish(state: Self::State) -> Result<(Self::Part1, Self::Part2), ParseError>;
+}
+
+/// feed `reader` through `S` one line at a time
+///
+/// Parsing and solving happen together, so all of the time is reported as parse time and the
+/// parts don't have a time of their own.
+pub fn stream<S: Streaming>(reader: &mut dyn BufRead, parts: &[Part]) -> Result<Report, Error> {
+    let start = Instant::now();
+    let mut state = S::State::default();
+    let mut line = String::new();
+    let mut number = 0;
+
+    loop {
+        line.clear();
+        let read = reader.read_line(&mut line).map_err(|source| Error::Io {
+            path: PathBuf::from("input"),
+            source,
+        })?;
+        if read == 0 {
+            break;
+        }
+        number += 1;
+
+        let text = line.trim_end_matches('\n').trim_end_matches('\r');
+        S::feed(&mut state, text).map_err(|e| ParseError {
+            day: S::DAY,
+            line: number,
+            ..e
+        })?;
+    }
+
+    let (part1, part2) = S::finish(state).map_err(|e| ParseError {
+        day: S::DAY,
+        line: number + 1,
+        ..e
+    })?;
+    let parse = start.elapsed();
+
+    let answers = parts
+        .iter()
+        .map(|part| Answer {
+            day: S::DAY,
+            part: *part,
+            value: match part {
+                Part::One => part1.to_string(),
+                Part::Two => part2.to_string(),
+            },
+            duration: None,
+        })
+        .collect();
+
+    Ok(Report {
+        day: S::DAY,
+        parse,
+        answers,
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{Part, fixture::Sum, solve, stream::stream};
+
+    #[test]
+    fn it_should_match_solving_in_one_go() {
+        let input = "1\r\n20\r\n300\r\n";
+        let streamed = stream::<Sum>(&mut input.as_bytes(), &Part::ALL).unwrap();
+        let solved = solve::<Sum>(input, &Part::ALL).unwrap();
+
+        let values =
+            |answers: &[crate::Answer]| answers.iter().map(|a| a.value.clone()).collect::<Vec<_>>();
+        assert_eq!(values(&streamed.answers), values(&solved.answers));
+        assert_eq!(values(&streamed.answers), ["321", "3"]);
+        assert!(
+            streamed
+                .answers
+                .iter()
+                .all(|answer| answer.duration.is_none())
+        );
+    }
+
+    #[test]
+    fn it_should_report_which_line_failed() {
+        let err = stream::<Sum>(&mut "1\n2\nx3\n".as_bytes(), &[Part::One]).unwrap_err();
+        let crate::Error::Parse(err) = err else {
+            panic!("expected a parse error, got {err}");
+        };
+
+        assert_eq!((err.line, err.column), (3, 1));
+        assert_eq!(err.text, "x3");
+    }
+}
@@ -0,0 +1,138 @@
+//! Generated puzzle inputs, roughly the size and shape of the real ones
+//!
+//! Everything is seeded so runs are comparable against saved baselines.
+
+/// xorshift, good enough for making up puzzle inputs
+pub struct Rng(u64);
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Self(seed.max(1))
+    }
+
+    pub fn next(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    /// in `low..=high`
+    pub fn range(&mut self, low: u64, high: u64) -> u64 {
+        low + self.next() % (high - low + 1)
+    }
+
+    pub fn chance(&mut self, percent: u64) -> bool {
+        self.next() % 100 < percent
+    }
+}
+
+pub fn day01() -> String {
+    let mut rng = Rng::new(1);
+    (0..4000)
+        .map(|_| {
+            let direction = if rng.chance(50) { 'L' } else { 'R' };
+            format!("{direction}{}", rng.range(1, 999))
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+pub fn day02() -> String {
+    let mut rng = Rng::new(2);
+    (0..35)
+        .map(|_| {
+            let start = rng.range(10, 9_000_000_000);
+            format!("{start}-{}", start + rng.range(0, 50_000))
+        })
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+pub fn day03() -> String {
+    let mut rng = Rng::new(3);
+    (0..200)
+        .map(|_| {
+            (0..100)
+                .map(|_| char::from(b'0' + rng.range(1, 9) as u8))
+                .collect::<String>()
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+pub fn day04() -> String {
+    let mut rng = Rng::new(4);
+    (0..140)
+        .map(|_| {
+            (0..140)
+                .map(|_| if rng.chance(65) { '@' } else { '.' })
+                .collect::<String>()
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+pub fn day05() -> String {
+    let mut rng = Rng::new(5);
+    let ranges: Vec<String> = (0..180)
+        .map(|_| {
+            let start = rng.range(1, 500_000_000_000_000);
+            format!("{start}-{}", start + rng.range(0, 10_000_000_000_000))
+        })
+        .collect();
+    let ids: Vec<String> = (0..1000)
+        .map(|_| rng.range(1, 500_000_000_000_000).to_string())
+        .collect();
+
+    format!("{}\n\n{}", ranges.join("\n"), ids.join("\n"))
+}
+
+pub fn day06() -> String {
+    let mut rng = Rng::new(6);
+    let mut rows = vec![String::new(); 4];
+    let mut ops = String::new();
+
+    for problem in 0..1000 {
+        // part 2 reads down the columns and can't cope with gaps inside a number,
+        // so every number in a problem has the same width
+        let width = rng.range(1, 4) as usize;
+        let low = 10u64.pow(width as u32 - 1);
+        // problems are separated by a column of spaces
+        let gap = if problem == 0 { "" } else { " " };
+
+        for row in rows.iter_mut() {
+            row.push_str(gap);
+            row.push_str(&rng.range(low, low * 10 - 1).to_string());
+        }
+
+        let op = if rng.chance(50) { '*' } else { '+' };
+        ops.push_str(gap);
+        ops.push_str(&format!("{op:<width$}"));
+    }
+
+    rows.push(ops);
+    rows.join("\n")
+}
+
+pub fn day07() -> String {
+    let mut rng = Rng::new(7);
+    let width = 141;
+
+    (0..142)
+        .map(|y| {
+            (0..width)
+                .map(|x| {
+                    if y == 0 && x == width / 2 {
+                        'S'
+                    } else if y > 0 && y % 2 == 0 && x % 2 == 0 && x > 0 && x < width - 1 {
+                        if rng.chance(40) { '^' } else { '.' }
+                    } else {
+                        '.'
+                    }
+                })
+                .collect::<String>()
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
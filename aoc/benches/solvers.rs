@@ -0,0 +1,40 @@
+//! Benchmarks for every day, on generated inputs
+//!
+//! `cargo bench -p aoc -- --save-baseline main` to record a baseline, then
+//! `cargo bench -p aoc -- --baseline main` to compare a change against it.
+
+use std::hint::black_box;
+
+use aoc_core::Solution;
+use criterion::{Criterion, criterion_group, criterion_main};
+
+mod inputs;
+
+fn bench_day<S: Solution>(c: &mut Criterion, input: &str) {
+    let mut group = c.benchmark_group(format!("day{:02}", S::DAY));
+    let parsed = S::parse(input).expect("generated inputs should parse");
+
+    group.bench_function("parse", |b| b.iter(|| S::parse(black_box(input))));
+    group.bench_function("part1", |b| b.iter(|| S::part1(black_box(&parsed))));
+    group.bench_function("part2", |b| b.iter(|| S::part2(black_box(&parsed))));
+
+    group.finish();
+}
+
+fn solvers(c: &mut Criterion) {
+    bench_day::<day01::Day01>(c, &inputs::day01());
+    bench_day::<day02::Day02>(c, &inputs::day02());
+    bench_day::<day03::Day03>(c, &inputs::day03());
+    bench_day::<day04::Day04>(c, &inputs::day04());
+    bench_day::<day05::Day05>(c, &inputs::day05());
+    bench_day::<day06::Day06>(c, &inputs::day06());
+    bench_day::<day07::Day07>(c, &inputs::day07());
+}
+
+criterion_group! {
+    name = benches;
+    // the brute force days are slow, keep the whole suite to a few minutes
+    config = Criterion::default().sample_size(10);
+    targets = solvers
+}
+criterion_main!(benches);
@@ -0,0 +1,162 @@
+use std::path::PathBuf;
+
+use aoc_core::{Error, Options, Part, flag_value};
+
+pub const USAGE: &str =
+    "aoc run <day|all> [--part <1|2>] [--input <path|->] [--answers <path>] [--time] [--examples] [--stream]
+        [--format <text|json>]
+aoc new <day>";
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Days {
+    All,
+    One(u8),
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Command {
+    Run {
+        days: Days,
+        part: Option<Part>,
+        input: Option<PathBuf>,
+        options: Options,
+    },
+    /// scaffold a new dayNN crate
+    New {
+        day: u8,
+    },
+    Help,
+}
+
+/// parse everything after the binary name
+pub fn parse(args: impl IntoIterator<Item = String>) -> Result<Command, Error> {
+    let mut args = args.into_iter();
+
+    match args.next().as_deref() {
+        Some("run") => parse_run(args),
+        Some("new") => match (args.next(), args.next()) {
+            (Some(day), None) => Ok(Command::New {
+                day: parse_day(&day)?,
+            }),
+            _ => Err(Error::Usage(USAGE.to_string())),
+        },
+        Some("help" | "--help" | "-h") | None => Ok(Command::Help),
+        Some(other) => Err(Error::Usage(format!("unknown command '{other}'"))),
+    }
+}
+
+fn parse_run(mut args: impl Iterator<Item = String>) -> Result<Command, Error> {
+    let days = match args.next().as_deref() {
+        Some("all") => Days::All,
+        Some(day) => Days::One(parse_day(day)?),
+        None => return Err(Error::Usage(USAGE.to_string())),
+    };
+
+    let mut part = None;
+    let mut input = None;
+    let mut options = Options::default();
+
+    while let Some(flag) = args.next() {
+        if options.parse_flag(&flag, &mut args)? {
+            continue;
+        }
+
+        match flag.as_str() {
+            "--part" | "-p" => part = Some(flag_value(&flag, args.next())?.parse()?),
+            "--input" | "-i" => input = Some(PathBuf::from(flag_value(&flag, args.next())?)),
+            _ => return Err(Error::Usage(format!("unknown flag '{flag}'"))),
+        }
+    }
+
+    if days == Days::All && input.is_some() {
+        return Err(Error::Usage(
+            "--input needs a single day, 'all' uses inputs/dayNN.txt".to_string(),
+        ));
+    }
+
+    if options.examples && input.is_some() {
+        return Err(Error::Usage(
+            "--examples uses examples/dayNN/*.txt, it can't be combined with --input".to_string(),
+        ));
+    }
+
+    Ok(Command::Run {
+        days,
+        part,
+        input,
+        options,
+    })
+}
+
+fn parse_day(day: &str) -> Result<u8, Error> {
+    day.parse::<u8>()
+        .ok()
+        .filter(|day| (1..=25).contains(day))
+        .ok_or_else(|| Error::Usage(format!("day must be 1-25 or 'all', got '{day}'")))
+}
+
+#[cfg(test)]
+mod test {
+    use std::path::PathBuf;
+
+    use aoc_core::{Format, Options, Part};
+
+    use crate::cli::{Command, Days, parse};
+
+    fn args(line: &str) -> Vec<String> {
+        line.split_whitespace().map(String::from).collect()
+    }
+
+    #[test]
+    fn it_should_parse_a_single_day() {
+        assert_eq!(
+            parse(args("run 5 --part 2 --input day05/input.txt")).unwrap(),
+            Command::Run {
+                days: Days::One(5),
+                part: Some(Part::Two),
+                input: Some(PathBuf::from("day05/input.txt")),
+                options: Options::default(),
+            }
+        );
+    }
+
+    #[test]
+    fn it_should_parse_all_days() {
+        assert_eq!(
+            parse(args("run all --answers mine.toml")).unwrap(),
+            Command::Run {
+                days: Days::All,
+                part: None,
+                input: None,
+                options: Options {
+                    answers: Some(PathBuf::from("mine.toml")),
+                    time: false,
+                    examples: false,
+                    stream: false,
+                    format: Format::Text,
+                },
+            }
+        );
+    }
+
+    #[test]
+    fn it_should_parse_new() {
+        assert_eq!(parse(args("new 8")).unwrap(), Command::New { day: 8 });
+    }
+
+    #[test]
+    fn it_should_reject_bad_args() {
+        assert!(parse(args("run")).is_err());
+        assert!(parse(args("run 26")).is_err());
+        assert!(parse(args("run 1 --part 3")).is_err());
+        assert!(parse(args("run 1 --input")).is_err());
+        assert!(parse(args("run all --input x.txt")).is_err());
+        assert!(parse(args("run 1 --examples --input x.txt")).is_err());
+        assert!(parse(args("run 1 --format yaml")).is_err());
+        assert!(parse(args("jump 1")).is_err());
+        assert!(parse(args("new")).is_err());
+        assert!(parse(args("new all")).is_err());
+        assert!(parse(args("new 8 9")).is_err());
+        assert_eq!(parse(args("")).unwrap(), Command::Help);
+    }
+}
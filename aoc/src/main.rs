@@ -0,0 +1,199 @@
+use std::{
+    env::{args, current_dir},
+    path::PathBuf,
+    process::ExitCode,
+};
+
+use aoc_core::{Case, Error, Format, Options, Part, error_json, examples_dir, run_cases};
+
+use crate::{
+    cli::{Command, Days, USAGE},
+    table::Table,
+};
+
+mod cli;
+mod registry;
+mod scaffold;
+mod table;
+
+fn main() -> ExitCode {
+    match cli::parse(args().skip(1)).and_then(execute) {
+        Ok(true) => ExitCode::SUCCESS,
+        Ok(false) => ExitCode::FAILURE,
+        Err(e) => {
+            eprintln!("aoc: {e}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// returns whether everything succeeded, including matching the answers file
+fn execute(command: Command) -> Result<bool, Error> {
+    match command {
+        Command::Help => {
+            println!("{USAGE}");
+            Ok(true)
+        }
+        Command::Run {
+            days,
+            part,
+            input,
+            options,
+        } => run(days, part, input, options),
+        Command::New { day } => {
+            let cwd = current_dir().map_err(|source| Error::Io {
+                path: PathBuf::from("."),
+                source,
+            })?;
+            let root = scaffold::find_workspace(&cwd)?;
+
+            for path in scaffold::new_day(&root, day)? {
+                println!("wrote {}", path.display());
+            }
+            Ok(true)
+        }
+    }
+}
+
+fn run(
+    days: Days,
+    part: Option<Part>,
+    input: Option<PathBuf>,
+    options: Options,
+) -> Result<bool, Error> {
+    let runners = match days {
+        Days::All => registry::DAYS.to_vec(),
+        Days::One(day) => match registry::find(day) {
+            Some(runner) => vec![runner],
+            None => {
+                let e = Error::Usage(format!("day {day} isn't registered"));
+                return fail(&[day], e, &options);
+            }
+        },
+    };
+    let parts = part.map_or(Part::ALL.to_vec(), |part| vec![part]);
+    let answers = match options.load_answers() {
+        Ok(answers) => answers,
+        Err(e) => {
+            let days: Vec<u8> = runners.iter().map(|runner| runner.day()).collect();
+            return fail(&days, e, &options);
+        }
+    };
+
+    let mut headers = vec!["day"];
+    if options.examples {
+        headers.push("example");
+    }
+    headers.extend(["part", "answer"]);
+    if answers.is_some() || options.examples {
+        headers.push("status");
+    }
+    if options.time {
+        headers.extend(["parse", "time"]);
+    }
+
+    let mut table = Table::new(&headers);
+    let mut ok = true;
+
+    for runner in runners {
+        let cases = match run_cases(runner, input.as_deref(), &parts, &options, answers.as_ref()) {
+            Ok(cases) => cases,
+            Err(e) => vec![Case {
+                day: runner.day(),
+                example: options.examples.then(|| "-".to_string()),
+                result: Err(e),
+            }],
+        };
+
+        if cases.is_empty() {
+            // a check where nothing was checked isn't a pass, the same as for the dayNN binaries
+            ok = false;
+            let message = format!(
+                "no examples in {}",
+                examples_dir()
+                    .join(format!("day{:02}", runner.day()))
+                    .display()
+            );
+            match options.format {
+                Format::Json => {
+                    let e = Error::Usage(message);
+                    println!("{}", error_json(runner.day(), &e, Some("-")));
+                }
+                Format::Text => table.push(vec![
+                    runner.day().to_string(),
+                    "-".to_string(),
+                    "-".to_string(),
+                    message,
+                ]),
+            }
+            continue;
+        }
+
+        for case in cases {
+            ok &= case.passed();
+            if options.format == Format::Json {
+                for record in case.json() {
+                    println!("{record}");
+                }
+                continue;
+            }
+
+            let cells = |answer: &str, part: String| {
+                let mut row = vec![case.day.to_string()];
+                row.extend(case.example.clone());
+                row.extend([part, answer.to_string()]);
+                row
+            };
+
+            match &case.result {
+                Ok(checked) => {
+                    for (answer, status) in &checked.answers {
+                        let mut row = cells(&answer.value, answer.part.to_string());
+                        if let Some(status) = status {
+                            row.push(status.to_string());
+                        }
+                        if options.time {
+                            row.extend([
+                                format!("{:.2?}", checked.parse),
+                                answer
+                                    .duration
+                                    .map_or("-".to_string(), |duration| format!("{duration:.2?}")),
+                            ]);
+                        }
+
+                        table.push(row);
+                    }
+                }
+                Err(e) => {
+                    // parse errors come with a multi-line diagnostic that won't fit in the table
+                    let message = e.to_string();
+                    if message.contains('\n') {
+                        eprintln!("{message}\n");
+                    }
+                    table.push(cells(
+                        message.lines().next().unwrap_or_default(),
+                        "-".to_string(),
+                    ));
+                }
+            }
+        }
+    }
+
+    if options.format == Format::Text {
+        print!("{table}");
+    }
+    Ok(ok)
+}
+
+/// an error from before any of `days` ran, which `--format json` still reports as a record
+/// for each of them, the same as the dayNN binaries
+fn fail(days: &[u8], e: Error, options: &Options) -> Result<bool, Error> {
+    if options.format != Format::Json {
+        return Err(e);
+    }
+
+    for day in days {
+        println!("{}", error_json(*day, &e, None));
+    }
+    Ok(false)
+}
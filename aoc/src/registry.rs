@@ -0,0 +1,33 @@
+use aoc_core::Runner;
+
+/// every day the `aoc` binary knows about, in order
+pub static DAYS: &[&dyn Runner] = &[
+    &day01::Day01,
+    &day02::Day02,
+    &day03::Day03,
+    &day04::Day04,
+    &day05::Day05,
+    &day06::Day06,
+    &day07::Day07,
+];
+
+pub fn find(day: u8) -> Option<&'static dyn Runner> {
+    DAYS.iter().copied().find(|runner| runner.day() == day)
+}
+
+#[cfg(test)]
+mod test {
+    use crate::registry::{DAYS, find};
+
+    #[test]
+    fn it_should_register_days_in_order() {
+        // `aoc new` relies on this to slot new days in
+        assert!(DAYS.windows(2).all(|pair| pair[0].day() < pair[1].day()));
+    }
+
+    #[test]
+    fn it_should_find_days() {
+        assert_eq!(find(5).map(|runner| runner.day()), Some(5));
+        assert!(find(26).is_none());
+    }
+}
@@ -0,0 +1,329 @@
+use std::{
+    fs::{self, OpenOptions},
+    io::Write,
+    path::{Path, PathBuf},
+};
+
+use aoc_core::{Error, read_input};
+
+/// new days start from `example.rs`, with `Day00` swapped for the real day
+const TEMPLATE: &str = include_str!("../../example.rs");
+
+/// [`aoc_core::find_workspace`], for commands that can't do anything outside of one
+pub fn find_workspace(start: &Path) -> Result<PathBuf, Error> {
+    aoc_core::find_workspace(start)
+        .ok_or_else(|| Error::Workspace("run `aoc new` from inside the workspace".to_string()))
+}
+
+/// create dayNN and hook it up to the workspace and the `aoc` registry
+/// returns every file written
+pub fn new_day(root: &Path, day: u8) -> Result<Vec<PathBuf>, Error> {
+    let name = format!("day{day:02}");
+    let dir = root.join(&name);
+
+    if dir.exists() {
+        return Err(Error::Workspace(format!(
+            "{name} already exists, not overwriting it"
+        )));
+    }
+
+    // work out every edit before writing anything so a problem doesn't leave things half done
+    let workspace = root.join("Cargo.toml");
+    let runner = root.join("aoc").join("Cargo.toml");
+    let registry = root.join("aoc").join("src").join("registry.rs");
+
+    let edits = [
+        (
+            workspace.clone(),
+            add_member(&read_input(&workspace)?, &name)?,
+        ),
+        (
+            runner.clone(),
+            add_dependency(&read_input(&runner)?, &name)?,
+        ),
+        (registry.clone(), register(&read_input(&registry)?, day)?),
+    ];
+    let files = [
+        (dir.join("Cargo.toml"), manifest(&name)),
+        (dir.join("src").join("lib.rs"), lib(day)),
+        (dir.join("src").join("main.rs"), main(day)),
+    ];
+
+    let io_error = |path: &Path| {
+        let path = path.to_path_buf();
+        move |source| Error::Io { path, source }
+    };
+
+    fs::create_dir_all(dir.join("src")).map_err(io_error(&dir))?;
+    for (path, contents) in &files {
+        OpenOptions::new()
+            .write(true)
+            .create_new(true)
+            .open(path)
+            .and_then(|mut file| file.write_all(contents.as_bytes()))
+            .map_err(io_error(path))?;
+    }
+    for (path, contents) in &edits {
+        fs::write(path, contents).map_err(io_error(path))?;
+    }
+
+    Ok(files
+        .into_iter()
+        .chain(edits)
+        .map(|(path, _)| path)
+        .collect())
+}
+
+fn manifest(name: &str) -> String {
+    format!(
+        r#"[package]
+name = "{name}"
+version = "0.1.0"
+edition = "2024"
+
+[dependencies]
+aoc-core = {{ path = "../aoc-core" }}
+"#
+    )
+}
+
+fn lib(day: u8) -> String {
+    TEMPLATE
+        .replace("Day00", &format!("Day{day:02}"))
+        .replace("DAY: u8 = 0;", &format!("DAY: u8 = {day};"))
+}
+
+fn main(day: u8) -> String {
+    format!(
+        r#"use std::process::ExitCode;
+
+use day{day:02}::Day{day:02};
+
+fn main() -> ExitCode {{
+    aoc_core::run(&Day{day:02})
+}}
+"#
+    )
+}
+
+/// add `name` to the workspace `members = [...]`
+fn add_member(manifest: &str, name: &str) -> Result<String, Error> {
+    let missing = || Error::Workspace("no members list in the workspace Cargo.toml".to_string());
+
+    let start = manifest
+        .find("members")
+        .and_then(|i| manifest[i..].find('[').map(|j| i + j + 1))
+        .ok_or_else(missing)?;
+    let end = manifest[start..]
+        .find(']')
+        .map(|i| start + i)
+        .ok_or_else(missing)?;
+    let members = &manifest[start..end];
+
+    if members.contains(&format!("\"{name}\"")) {
+        return Err(Error::Workspace(format!(
+            "{name} is already a workspace member"
+        )));
+    }
+
+    let insert_at = start + members.trim_end().len();
+    let separator = match members.trim_end().chars().last() {
+        None => "",
+        Some(',') => " ",
+        Some(_) => ", ",
+    };
+
+    Ok(format!(
+        "{}{separator}\"{name}\"{}",
+        &manifest[..insert_at],
+        &manifest[insert_at..]
+    ))
+}
+
+/// add a path dependency on `name` after the other days in `[dependencies]`
+fn add_dependency(manifest: &str, name: &str) -> Result<String, Error> {
+    let section = manifest
+        .find("[dependencies]\n")
+        .ok_or_else(|| Error::Workspace("no [dependencies] in aoc/Cargo.toml".to_string()))?;
+    let body_start = section + "[dependencies]\n".len();
+    let body_end = manifest[body_start..]
+        .find("\n[")
+        .map_or(manifest.len(), |i| body_start + i + 1);
+    let body = &manifest[body_start..body_end];
+
+    if body
+        .lines()
+        .any(|line| line.starts_with(&format!("{name} ")))
+    {
+        return Err(Error::Workspace(format!("aoc already depends on {name}")));
+    }
+
+    // after the last day, or at the top if there aren't any yet
+    let insert_at = body
+        .lines()
+        .scan(body_start, |end, line| {
+            *end += line.len() + 1;
+            Some((*end, line))
+        })
+        .filter(|(_, line)| line.starts_with("day"))
+        .last()
+        .map_or(body_start, |(end, _)| end);
+
+    Ok(format!(
+        "{}{name} = {{ path = \"../{name}\" }}\n{}",
+        &manifest[..insert_at],
+        &manifest[insert_at..]
+    ))
+}
+
+/// add the day to `DAYS` in registry.rs, keeping it in order
+fn register(registry: &str, day: u8) -> Result<String, Error> {
+    let missing = || Error::Workspace("no DAYS list in aoc/src/registry.rs".to_string());
+
+    let start = registry.find("pub static DAYS").ok_or_else(missing)?;
+    let end = registry[start..]
+        .find("\n];")
+        .map(|i| start + i + 1)
+        .ok_or_else(missing)?;
+
+    let entry_day = |line: &str| {
+        line.trim()
+            .strip_prefix("&day")
+            .and_then(|rest| rest.get(..2))
+            .and_then(|n| n.parse::<u8>().ok())
+    };
+
+    let mut offset = start;
+    let mut insert_at = end;
+    for line in registry[start..end].split_inclusive('\n') {
+        match entry_day(line) {
+            Some(existing) if existing == day => {
+                return Err(Error::Workspace(format!("day {day} is already registered")));
+            }
+            Some(existing) if existing > day => {
+                insert_at = offset;
+                break;
+            }
+            _ => {}
+        }
+        offset += line.len();
+    }
+
+    Ok(format!(
+        "{}    &day{day:02}::Day{day:02},\n{}",
+        &registry[..insert_at],
+        &registry[insert_at..]
+    ))
+}
+
+#[cfg(test)]
+mod test {
+    use std::{env::temp_dir, fs, process};
+
+    use crate::scaffold::{add_dependency, add_member, find_workspace, lib, new_day, register};
+
+    #[test]
+    fn it_should_add_workspace_members() {
+        assert_eq!(
+            add_member("members = [\"aoc\", \"day07\"]\n", "day08").unwrap(),
+            "members = [\"aoc\", \"day07\", \"day08\"]\n"
+        );
+        assert_eq!(
+            add_member("members = [\n    \"day07\",\n]\n", "day08").unwrap(),
+            "members = [\n    \"day07\", \"day08\"\n]\n"
+        );
+        assert_eq!(
+            add_member("members = []", "day01").unwrap(),
+            "members = [\"day01\"]"
+        );
+        assert!(add_member("members = [\"day08\"]", "day08").is_err());
+        assert!(add_member("[workspace]", "day08").is_err());
+    }
+
+    #[test]
+    fn it_should_add_dependencies_after_the_days() {
+        let manifest = "[dependencies]
+aoc-core = { path = \"../aoc-core\" }
+day07 = { path = \"../day07\" }
+
+[dev-dependencies]
+";
+        assert_eq!(
+            add_dependency(manifest, "day08").unwrap(),
+            "[dependencies]
+aoc-core = { path = \"../aoc-core\" }
+day07 = { path = \"../day07\" }
+day08 = { path = \"../day08\" }
+
+[dev-dependencies]
+"
+        );
+        assert!(add_dependency(manifest, "day07").is_err());
+    }
+
+    #[test]
+    fn it_should_register_days_in_order() {
+        let registry = "pub static DAYS: &[&dyn Runner] = &[
+    &day01::Day01,
+    &day03::Day03,
+];
+";
+        assert_eq!(
+            register(registry, 2).unwrap(),
+            "pub static DAYS: &[&dyn Runner] = &[
+    &day01::Day01,
+    &day02::Day02,
+    &day03::Day03,
+];
+"
+        );
+        assert!(register(&register(registry, 4).unwrap(), 4).is_err());
+        assert!(register(&register(registry, 4).unwrap(), 3).is_err());
+        assert!(
+            register(registry, 4)
+                .unwrap()
+                .contains("&day03::Day03,\n    &day04::Day04,\n];")
+        );
+    }
+
+    #[test]
+    fn it_should_fill_in_the_template() {
+        let source = lib(8);
+        assert!(source.contains("pub struct Day08;"));
+        assert!(source.contains("const DAY: u8 = 8;"));
+        assert!(!source.contains("Day00"));
+    }
+
+    #[test]
+    fn it_should_scaffold_a_day_once() {
+        let root = temp_dir().join(format!("aoc-scaffold-test-{}", process::id()));
+        fs::create_dir_all(root.join("aoc").join("src")).unwrap();
+        fs::write(
+            root.join("Cargo.toml"),
+            "[workspace]\nmembers = [\"aoc\"]\n",
+        )
+        .unwrap();
+        fs::write(root.join("aoc").join("Cargo.toml"), "[dependencies]\n").unwrap();
+        fs::write(
+            root.join("aoc").join("src").join("registry.rs"),
+            "pub static DAYS: &[&dyn Runner] = &[\n];\n",
+        )
+        .unwrap();
+
+        assert_eq!(find_workspace(&root.join("aoc")).unwrap(), root);
+
+        let written = new_day(&root, 8).unwrap();
+        assert_eq!(written.len(), 6);
+        assert!(root.join("day08").join("src").join("lib.rs").exists());
+        assert!(
+            fs::read_to_string(root.join("Cargo.toml"))
+                .unwrap()
+                .contains("\"aoc\", \"day08\"")
+        );
+
+        let again = new_day(&root, 8);
+        fs::remove_dir_all(&root).unwrap();
+
+        assert!(again.unwrap_err().to_string().contains("already exists"));
+    }
+}
@@ -0,0 +1,211 @@
+use crate::{Direction, Rotation};
+
+/// What counts as hitting a target
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Counting {
+    /// only where a rotation stops
+    Landing,
+    /// every click that points at a target, including the one a rotation stops on
+    Passing,
+}
+
+/// A combination lock dial numbered `0..positions`
+///
+/// Part 1 and 2 are the [`Dial::part1`] and [`Dial::part2`] presets, other locks can be
+/// built up from [`Dial::new`]:
+///
+/// ```
+/// use day01::{Counting, Dial};
+///
+/// let dial = Dial::new(40).starting_at(0).targets([10, 30]).counting(Counting::Passing);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Dial {
+    positions: i64,
+    start: i64,
+    /// sorted, no duplicates
+    targets: Vec<i64>,
+    counting: Counting,
+}
+
+impl Dial {
+    /// starts at 0 and counts landing on 0
+    pub fn new(positions: u32) -> Self {
+        assert!(positions > 0, "a dial needs at least one position");
+
+        Self {
+            positions: i64::from(positions),
+            start: 0,
+            targets: vec![0],
+            counting: Counting::Landing,
+        }
+    }
+
+    /// 100 positions, starting at 50, counting rotations that stop on 0
+    pub fn part1() -> Self {
+        Self::new(100).starting_at(50)
+    }
+
+    /// like part 1 but counting every click past 0
+    pub fn part2() -> Self {
+        Self::part1().counting(Counting::Passing)
+    }
+
+    /// positions outside the dial wrap around
+    pub fn starting_at(mut self, start: i64) -> Self {
+        self.start = start.rem_euclid(self.positions);
+        self
+    }
+
+    pub fn targets(mut self, targets: impl IntoIterator<Item = i64>) -> Self {
+        self.targets = targets
+            .into_iter()
+            .map(|target| target.rem_euclid(self.positions))
+            .collect();
+        self.targets.sort_unstable();
+        self.targets.dedup();
+        self
+    }
+
+    pub fn counting(mut self, counting: Counting) -> Self {
+        self.counting = counting;
+        self
+    }
+
+    pub fn positions(&self) -> i64 {
+        self.positions
+    }
+
+    pub fn start(&self) -> i64 {
+        self.start
+    }
+
+    /// how hits are counted
+    pub fn mode(&self) -> Counting {
+        self.counting
+    }
+
+    pub fn is_target(&self, pos: i64) -> bool {
+        self.targets
+            .binary_search(&pos.rem_euclid(self.positions))
+            .is_ok()
+    }
+
+    /// where the dial ends up after one rotation from `pos`, and how many targets it hit
+    /// worked out directly, so huge distances cost the same as small ones
+    pub fn turn(&self, pos: i64, rotation: &Rotation) -> (i64, u64) {
+        let num = i64::from(rotation.distance);
+        let end = match rotation.direction {
+            Direction::Left => pos - num,
+            Direction::Right => pos + num,
+        };
+        let end_pos = end.rem_euclid(self.positions);
+
+        // every position the dial points at on the way, unwrapped
+        let (first, last) = match rotation.direction {
+            Direction::Right => (pos + 1, end),
+            Direction::Left => (end, pos - 1),
+        };
+
+        let hits = match self.counting {
+            Counting::Landing => u64::from(self.is_target(end_pos)),
+            Counting::Passing => self
+                .targets
+                .iter()
+                .map(|target| {
+                    // shift so the target sits on multiples of `positions`, then count those
+                    let below = |n: i64| (n - target).div_euclid(self.positions);
+                    (below(last) - below(first - 1)) as u64
+                })
+                .sum(),
+        };
+
+        (end_pos, hits)
+    }
+
+    /// how many targets were hit across every rotation, starting from `start`
+    pub fn count(&self, rotations: &[Rotation]) -> u64 {
+        let mut pos = self.start;
+        let mut count = 0;
+        for rotation in rotations {
+            let hits;
+            (pos, hits) = self.turn(pos, rotation);
+            count += hits;
+        }
+        count
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use proptest::prelude::*;
+
+    use crate::{Counting, Dial, Direction, Rotation, parse_rotations};
+
+    /// one click at a time, to check `Dial::turn` against
+    fn turn_stepped(dial: &Dial, mut pos: i64, rotation: &Rotation) -> (i64, u64) {
+        let step = match rotation.direction {
+            Direction::Left => -1,
+            Direction::Right => 1,
+        };
+        let mut hits = 0;
+        for _ in 0..rotation.distance {
+            pos = (pos + step).rem_euclid(dial.positions);
+            if dial.counting == Counting::Passing && dial.targets.contains(&pos) {
+                hits += 1;
+            }
+        }
+        if dial.counting == Counting::Landing && dial.targets.contains(&pos) {
+            hits += 1;
+        }
+        (pos, hits)
+    }
+
+    #[test]
+    fn it_should_count_other_locks() {
+        let rotations = parse_rotations("R10\nR20\nL5\nL40").unwrap();
+        let dial = Dial::new(40).targets([10, 30]);
+
+        assert_eq!(dial.count(&rotations), 2);
+        // 10, 30, 10 and 30 again going back past them
+        assert_eq!(
+            dial.clone().counting(Counting::Passing).count(&rotations),
+            4
+        );
+        assert_eq!(Dial::new(40).targets([]).count(&rotations), 0);
+    }
+
+    #[test]
+    fn it_should_wrap_settings_onto_the_dial() {
+        let dial = Dial::new(10).starting_at(-1).targets([12, 2, 22]);
+        assert_eq!(dial.start(), 9);
+        assert_eq!(dial.targets, vec![2]);
+    }
+
+    proptest! {
+        #[test]
+        fn it_should_turn_like_stepping(
+            positions in 1..=100u32,
+            start in 0..100i64,
+            targets in prop::collection::vec(0..100i64, 0..4),
+            passing in any::<bool>(),
+            left in any::<bool>(),
+            distance in 0..300i32,
+        ) {
+            let dial = Dial::new(positions)
+                .targets(targets)
+                .counting(if passing { Counting::Passing } else { Counting::Landing });
+            let pos = start % i64::from(positions);
+            let rotation = Rotation {
+                direction: if left { Direction::Left } else { Direction::Right },
+                distance,
+            };
+
+            prop_assert_eq!(dial.turn(pos, &rotation), turn_stepped(&dial, pos, &rotation));
+
+            // and the actual puzzle's dial, from anywhere on it
+            let part2 = Dial::part2();
+            prop_assert_eq!(part2.turn(start, &rotation), turn_stepped(&part2, start, &rotation));
+        }
+    }
+}
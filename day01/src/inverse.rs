@@ -0,0 +1,125 @@
+use crate::{Counting, Dial, Direction, Rotation};
+
+/// every start position from which `dial` hits its targets exactly `count` times
+pub fn starts_for_count(dial: &Dial, rotations: &[Rotation], count: u64) -> Vec<i64> {
+    (0..dial.positions())
+        .filter(|start| dial.clone().starting_at(*start).count(rotations) == count)
+        .collect()
+}
+
+/// The fewest rotations that make `dial` hit its targets exactly `count` times from its start,
+/// handy for building test inputs
+///
+/// Landing on a target only counts once per rotation, so that takes `count` rotations.
+/// Passing can rack up any count in one go, split up only when it's too far for an `i32`.
+/// `None` if the dial has no targets to hit.
+pub fn rotations_for_count(dial: &Dial, count: u64) -> Option<Vec<Rotation>> {
+    if count == 0 {
+        return Some(vec![]);
+    }
+
+    // clicks right from the start to each target, one under the start is a full turn away
+    let offsets: Vec<i64> = (1..=dial.positions())
+        .filter(|offset| dial.is_target(dial.start() + offset))
+        .collect();
+    let first = *offsets.first()?;
+
+    match dial.mode() {
+        Counting::Landing => {
+            // reach a target, then go all the way round back to it each time
+            let mut rotations = vec![right(first)];
+            rotations.extend((1..count).map(|_| right(dial.positions())));
+            Some(rotations)
+        }
+        Counting::Passing => {
+            // stop on the count-th target passed going right
+            let per_lap = offsets.len() as u64;
+            let laps = i64::try_from((count - 1) / per_lap).ok()?;
+            let mut distance = laps
+                .checked_mul(dial.positions())?
+                .checked_add(offsets[((count - 1) % per_lap) as usize])?;
+
+            let mut rotations = vec![];
+            while distance > 0 {
+                let step = distance.min(i64::from(i32::MAX));
+                rotations.push(right(step));
+                distance -= step;
+            }
+            Some(rotations)
+        }
+    }
+}
+
+fn right(distance: i64) -> Rotation {
+    Rotation {
+        direction: Direction::Right,
+        distance: i32::try_from(distance).expect("split into rotations that fit"),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use proptest::prelude::*;
+
+    use crate::{
+        Counting, Dial,
+        inverse::{rotations_for_count, starts_for_count},
+        parse_rotations,
+    };
+
+    #[test]
+    fn it_should_find_every_start() {
+        let rotations = parse_rotations("L68\nL30\nR48\nL5\nR60\nL55\nL1\nL99\nR14\nL82").unwrap();
+
+        let starts = starts_for_count(&Dial::part1(), &rotations, 3);
+        assert!(starts.contains(&50));
+        for start in 0..100 {
+            let count = Dial::part1().starting_at(start).count(&rotations);
+            assert_eq!(starts.contains(&start), count == 3);
+        }
+
+        // nothing can pass 0 a thousand times in a few hundred clicks
+        assert!(starts_for_count(&Dial::part2(), &rotations, 1000).is_empty());
+    }
+
+    #[test]
+    fn it_should_generate_rotations() {
+        assert_eq!(
+            rotations_for_count(&Dial::part2(), 3).unwrap(),
+            parse_rotations("R250").unwrap()
+        );
+        assert_eq!(
+            rotations_for_count(&Dial::part1(), 3).unwrap(),
+            parse_rotations("R50\nR100\nR100").unwrap()
+        );
+        assert_eq!(rotations_for_count(&Dial::part1(), 0), Some(vec![]));
+        assert_eq!(rotations_for_count(&Dial::new(10).targets([]), 1), None);
+
+        // too far for one rotation
+        let rotations = rotations_for_count(&Dial::part2(), 50_000_000).unwrap();
+        assert_eq!(rotations.len(), 3);
+        assert_eq!(Dial::part2().count(&rotations), 50_000_000);
+    }
+
+    proptest! {
+        #[test]
+        fn it_should_generate_exactly_the_count(
+            positions in 1..50u32,
+            start in 0..50i64,
+            targets in prop::collection::vec(0..50i64, 1..4),
+            passing in any::<bool>(),
+            count in 0..500u64,
+        ) {
+            let dial = Dial::new(positions)
+                .starting_at(start)
+                .targets(targets)
+                .counting(if passing { Counting::Passing } else { Counting::Landing });
+
+            let rotations = rotations_for_count(&dial, count).unwrap();
+            prop_assert_eq!(dial.count(&rotations), count);
+            if !passing {
+                prop_assert_eq!(rotations.len() as u64, count);
+            }
+        }
+    }
+}
@@ -0,0 +1,198 @@
+use std::str::FromStr;
+
+use aoc_core::{ParseError, Solution, Streamer, Streaming, stream};
+
+mod dial;
+mod inverse;
+mod lock;
+mod program;
+mod summary;
+mod trace;
+
+pub use dial::{Counting, Dial};
+pub use inverse::{rotations_for_count, starts_for_count};
+pub use lock::{Instruction, Lock};
+pub use program::{Command, Program};
+pub use summary::{Summary, count_parallel};
+pub use trace::{ReplayError, Step, Trace, check_trace, write_trace};
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const DAY: u8 = 1;
+
+    type Input<'a> = Vec<Rotation>;
+    type Part1 = u64;
+    type Part2 = u64;
+
+    const STREAM: Option<Streamer> = Some(stream::<Self>);
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse_rotations(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Self::Part1 {
+        Dial::part1().count(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Self::Part2 {
+        Dial::part2().count(input)
+    }
+}
+
+/// Running totals for both parts, one rotation at a time
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Tally {
+    part1: Dial,
+    part2: Dial,
+    /// both dials turn the same way, they only count differently
+    pos: i64,
+    zeroes: u64,
+    clicks: u64,
+}
+
+impl Default for Tally {
+    fn default() -> Self {
+        Self {
+            pos: Dial::part1().start(),
+            part1: Dial::part1(),
+            part2: Dial::part2(),
+            zeroes: 0,
+            clicks: 0,
+        }
+    }
+}
+
+impl Streaming for Day01 {
+    type State = Tally;
+
+    fn feed(tally: &mut Self::State, line: &str) -> Result<(), ParseError> {
+        let rotation = line.trim_end().parse::<Rotation>()?;
+        let (_, zeroes) = tally.part1.turn(tally.pos, &rotation);
+        let (pos, clicks) = tally.part2.turn(tally.pos, &rotation);
+
+        tally.pos = pos;
+        tally.zeroes += zeroes;
+        tally.clicks += clicks;
+        Ok(())
+    }
+
+    fn finish(tally: Self::State) -> Result<(Self::Part1, Self::Part2), ParseError> {
+        Ok((tally.zeroes, tally.clicks))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Direction {
+    Left,
+    Right,
+}
+
+impl TryFrom<&str> for Direction {
+    type Error = ParseError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value {
+            "L" => Ok(Direction::Left),
+            "R" => Ok(Direction::Right),
+            _ => Err(ParseError::at(
+                value,
+                value,
+                format!("expected L or R, got '{value}'"),
+            )),
+        }
+    }
+}
+
+/// One line of input, eg. `L68`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Rotation {
+    pub direction: Direction,
+    pub distance: i32,
+}
+
+impl FromStr for Rotation {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let Some((direction, num)) = s.split_at_checked(1) else {
+            return Err(ParseError::at(s, s, "expected a rotation like L68"));
+        };
+
+        let direction = Direction::try_from(direction)?;
+        let distance = num
+            .parse::<i32>()
+            .ok()
+            .filter(|n| *n >= 0)
+            .ok_or_else(|| ParseError::at(s, num, format!("'{num}' isn't a distance")))?;
+
+        Ok(Rotation {
+            direction,
+            distance,
+        })
+    }
+}
+
+pub fn parse_rotations(input: &str) -> Result<Vec<Rotation>, ParseError> {
+    input_lines(input)
+        .map(|line| line.parse::<Rotation>().map_err(|e| e.within(input, line)))
+        .collect()
+}
+
+/// the lines of `input` without any trailing whitespace, which every parser here ignores
+pub(crate) fn input_lines(input: &str) -> impl Iterator<Item = &str> {
+    input.lines().map(str::trim_end)
+}
+
+/// how many rotations stop on 0, starting from `start`
+pub fn calc_password(start: i32, rotations: &[Rotation]) -> u64 {
+    Dial::part1().starting_at(i64::from(start)).count(rotations)
+}
+
+/// how many clicks point the dial at 0, starting from `start`
+pub fn calc_password_with_clicks(start: i32, rotations: &[Rotation]) -> u64 {
+    Dial::part2().starting_at(i64::from(start)).count(rotations)
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{Day01, Dial, Direction, Rotation, parse_rotations};
+
+    aoc_core::example_files!(Day01);
+
+    aoc_core::examples! {
+        Day01;
+        large_rotations: "L50\nR200" => (2, 3),
+    }
+
+    #[test]
+    fn it_should_report_bad_rotations() {
+        let err = parse_rotations("L68\nX30").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 1));
+        assert_eq!(err.message, "expected L or R, got 'X'");
+
+        let err = parse_rotations("L68\nR3O").unwrap_err();
+        assert_eq!((err.line, err.column, err.len), (2, 2, 2));
+
+        let err = parse_rotations("L68\n\nR30").unwrap_err();
+        assert_eq!(err.line, 2);
+
+        // trailing whitespace is fine though
+        assert_eq!(parse_rotations("L68 \r\nR30\t").unwrap().len(), 2);
+    }
+
+    #[test]
+    fn it_should_count_huge_rotations_without_stepping() {
+        let rotation = Rotation {
+            direction: Direction::Right,
+            distance: 1_000_000_000,
+        };
+        assert_eq!(Dial::part2().turn(50, &rotation), (50, 10_000_000));
+
+        let rotation = Rotation {
+            direction: Direction::Left,
+            distance: i32::MAX,
+        };
+        assert_eq!(Dial::part2().turn(0, &rotation), (53, 21_474_836));
+    }
+}
@@ -0,0 +1,203 @@
+use std::str::FromStr;
+
+use aoc_core::ParseError;
+
+use crate::{Dial, Direction, Rotation, input_lines};
+
+/// One line of lock input, eg. `2L15` turns dial 2 left by 15
+///
+/// The index is 0-based and can be left off to turn dial 0, so plain `L68` still works.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Instruction {
+    pub dial: usize,
+    pub rotation: Rotation,
+}
+
+impl FromStr for Instruction {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let split = s.find(|c: char| !c.is_ascii_digit()).unwrap_or(s.len());
+        let (index, rotation) = s.split_at(split);
+
+        let dial = match index {
+            "" => 0,
+            index => index
+                .parse()
+                .map_err(|_| ParseError::at(s, index, format!("'{index}' isn't a dial")))?,
+        };
+        let rotation = rotation
+            .parse::<Rotation>()
+            .map_err(|e| e.within(s, rotation))?;
+
+        Ok(Instruction { dial, rotation })
+    }
+}
+
+/// A row of dials where one wrapping past zero turns the next one a click, like an odometer
+///
+/// Turning right from the last position to 0 carries one click right into the next dial, and
+/// turning left from 0 to the last position borrows one click left. The last dial's carries
+/// are lost. Each dial counts hits with its own [`Counting`](crate::Counting) and targets.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Lock {
+    dials: Vec<Dial>,
+    positions: Vec<i64>,
+    hits: Vec<u64>,
+}
+
+impl Lock {
+    pub fn new(dials: impl IntoIterator<Item = Dial>) -> Self {
+        let dials: Vec<Dial> = dials.into_iter().collect();
+
+        Self {
+            positions: dials.iter().map(Dial::start).collect(),
+            hits: vec![0; dials.len()],
+            dials,
+        }
+    }
+
+    /// where every dial points now
+    pub fn combination(&self) -> &[i64] {
+        &self.positions
+    }
+
+    /// how many times each dial has hit one of its targets, including turns carried into it
+    pub fn hits(&self) -> &[u64] {
+        &self.hits
+    }
+
+    /// panics if the instruction's dial isn't part of the lock, [`Lock::run`] checks first
+    pub fn turn(&mut self, instruction: &Instruction) {
+        let mut index = instruction.dial;
+        let mut rotation = instruction.rotation;
+
+        while let Some(dial) = self.dials.get(index) {
+            let pos = self.positions[index];
+            let (end, hits) = dial.turn(pos, &rotation);
+            self.positions[index] = end;
+            self.hits[index] += hits;
+
+            let moved = match rotation.direction {
+                Direction::Right => pos + i64::from(rotation.distance),
+                Direction::Left => pos - i64::from(rotation.distance),
+            };
+            let wraps = moved.div_euclid(dial.positions()).unsigned_abs();
+            if wraps == 0 {
+                break;
+            }
+
+            rotation = Rotation {
+                direction: rotation.direction,
+                distance: i32::try_from(wraps).expect("fewer wraps than clicks"),
+            };
+            index += 1;
+        }
+    }
+
+    /// parse and apply every line of `input`
+    pub fn run(&mut self, input: &str) -> Result<(), ParseError> {
+        for line in input_lines(input) {
+            let instruction = line
+                .parse::<Instruction>()
+                .map_err(|e| e.within(input, line))?;
+
+            if instruction.dial >= self.dials.len() {
+                let digits =
+                    line.len() - line.trim_start_matches(|c: char| c.is_ascii_digit()).len();
+                return Err(ParseError::at(
+                    input,
+                    &line[..digits],
+                    format!(
+                        "dial {} isn't part of a {} dial lock",
+                        instruction.dial,
+                        self.dials.len()
+                    ),
+                ));
+            }
+
+            self.turn(&instruction);
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use aoc_core::Solution;
+
+    use crate::{Counting, Day01, Dial, Direction, Instruction, Lock, Rotation};
+
+    fn odometer(dials: usize) -> Lock {
+        Lock::new(vec![Dial::new(10).counting(Counting::Passing); dials])
+    }
+
+    #[test]
+    fn it_should_parse_instructions() {
+        assert_eq!(
+            "12R5".parse::<Instruction>().unwrap(),
+            Instruction {
+                dial: 12,
+                rotation: Rotation {
+                    direction: Direction::Right,
+                    distance: 5
+                }
+            }
+        );
+        assert_eq!("L68".parse::<Instruction>().unwrap().dial, 0);
+
+        let err = "2X15".parse::<Instruction>().unwrap_err();
+        assert_eq!(
+            (err.column, err.message.as_str()),
+            (2, "expected L or R, got 'X'")
+        );
+    }
+
+    #[test]
+    fn it_should_carry_into_the_next_dial() {
+        let mut lock = odometer(3);
+        lock.run("0R15\n0L6").unwrap();
+
+        // 0 -> 5 carrying one into dial 1, then back past 0 borrowing it again
+        assert_eq!(lock.combination(), [9, 0, 0]);
+        assert_eq!(lock.hits(), [2, 1, 0]);
+    }
+
+    #[test]
+    fn it_should_ripple_carries() {
+        let mut lock = Lock::new([
+            Dial::new(10).starting_at(9),
+            Dial::new(10).starting_at(9),
+            Dial::new(10).starting_at(9),
+        ]);
+        lock.run("0R1").unwrap();
+        assert_eq!(lock.combination(), [0, 0, 0]);
+        assert_eq!(lock.hits(), [1, 1, 1]);
+
+        lock.run("2R100\n1L1").unwrap();
+        assert_eq!(lock.combination(), [0, 9, 9]);
+    }
+
+    #[test]
+    fn it_should_match_part_2_with_one_dial() {
+        let input = "L68\nL30\nR48\nL5\nR60\nL55\nL1\nL99\nR14\nL82";
+        let mut lock = Lock::new([Dial::part2()]);
+        lock.run(input).unwrap();
+
+        let rotations = Day01::parse(input).unwrap();
+        assert_eq!(lock.hits(), [Day01::part2(&rotations)]);
+
+        // anything the solver reads
+        let mut lock = Lock::new([Dial::part2()]);
+        lock.run("L68 \nL30\t").unwrap();
+        assert_eq!(lock.hits(), [1]);
+    }
+
+    #[test]
+    fn it_should_report_missing_dials() {
+        let err = odometer(2).run("0R1\n5L3").unwrap_err();
+        assert_eq!((err.line, err.column, err.len), (2, 1, 1));
+        assert_eq!(err.message, "dial 5 isn't part of a 2 dial lock");
+    }
+}
@@ -0,0 +1,264 @@
+use std::str::FromStr;
+
+use aoc_core::ParseError;
+
+use crate::{Dial, Rotation};
+
+/// One instruction in a [`Program`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Command {
+    /// `L68` or `R48`
+    Rotate(Rotation),
+    /// `S50` points the dial straight at 50 without turning, so nothing is hit on the way
+    Set(i64),
+    /// `3x(L10 R5)` runs the body 3 times
+    Repeat { times: u32, body: Vec<Command> },
+}
+
+/// Rotations plus a bit more, eg.
+///
+/// ```text
+/// # open the safe
+/// S0
+/// 3x(L10 R5)   # wiggle it
+/// R50
+/// ```
+///
+/// Commands are split by whitespace or newlines, `#` comments out the rest of a line and
+/// blank lines are skipped. Plain puzzle input is a program too.
+///
+/// ```
+/// use day01::{Dial, Program};
+///
+/// let program: Program = "S0\n2x(R100)".parse().unwrap();
+/// assert_eq!(program.count(&Dial::part1()), 2);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Program {
+    pub commands: Vec<Command>,
+}
+
+impl FromStr for Program {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut tokens = tokens(s).into_iter();
+        let commands = parse_block(s, &mut tokens, None)?;
+        Ok(Program { commands })
+    }
+}
+
+impl Program {
+    /// how many targets `dial` hits running the whole program from its start
+    pub fn count(&self, dial: &Dial) -> u64 {
+        run(dial, dial.start(), &self.commands).1
+    }
+
+    /// where `dial` ends up after running the program from `pos`, and how many targets it hit
+    pub fn run(&self, dial: &Dial, pos: i64) -> (i64, u64) {
+        run(dial, pos, &self.commands)
+    }
+}
+
+fn run(dial: &Dial, mut pos: i64, commands: &[Command]) -> (i64, u64) {
+    let mut count = 0;
+    for command in commands {
+        let hits;
+        (pos, hits) = match command {
+            Command::Rotate(rotation) => dial.turn(pos, rotation),
+            Command::Set(to) => (to.rem_euclid(dial.positions()), 0),
+            Command::Repeat { times, body } => (0..*times).fold((pos, 0), |(pos, count), _| {
+                let (pos, hits) = run(dial, pos, body);
+                (pos, count + hits)
+            }),
+        };
+        count += hits;
+    }
+    (pos, count)
+}
+
+/// every word and bracket in `input`, as slices of it so errors can point at them
+fn tokens(input: &str) -> Vec<&str> {
+    let mut tokens = vec![];
+    for line in input.lines() {
+        let mut rest = line.split_once('#').map_or(line, |(code, _)| code);
+        loop {
+            rest = rest.trim_start();
+            let len = match rest.chars().next() {
+                None => break,
+                Some('(' | ')') => 1,
+                Some(_) => rest
+                    .find(|c: char| c.is_whitespace() || c == '(' || c == ')')
+                    .unwrap_or(rest.len()),
+            };
+            tokens.push(&rest[..len]);
+            rest = &rest[len..];
+        }
+    }
+    tokens
+}
+
+/// commands up to the `)` matching `open`, or the end of the input when there's no `open`
+fn parse_block<'a>(
+    input: &'a str,
+    tokens: &mut impl Iterator<Item = &'a str>,
+    open: Option<&'a str>,
+) -> Result<Vec<Command>, ParseError> {
+    let mut commands = vec![];
+
+    while let Some(token) = tokens.next() {
+        let command = match token.as_bytes()[0] {
+            b')' if open.is_some() => return Ok(commands),
+            b')' => {
+                return Err(ParseError::at(
+                    input,
+                    token,
+                    "')' without a repeat to close",
+                ));
+            }
+            b'L' | b'R' => Command::Rotate(
+                token
+                    .parse::<Rotation>()
+                    .map_err(|e| e.within(input, token))?,
+            ),
+            b'S' => {
+                let to = &token[1..];
+                let to =
+                    to.parse::<i64>().ok().filter(|n| *n >= 0).ok_or_else(|| {
+                        ParseError::at(input, to, format!("'{to}' isn't a position"))
+                    })?;
+                Command::Set(to)
+            }
+            b'0'..=b'9' => {
+                let Some(times) = token.strip_suffix('x') else {
+                    return Err(ParseError::at(
+                        input,
+                        token,
+                        format!("expected a repeat like 3x(...), got '{token}'"),
+                    ));
+                };
+                let times = times.parse::<u32>().map_err(|_| {
+                    ParseError::at(input, times, format!("'{times}' is too many repeats"))
+                })?;
+                match tokens.next() {
+                    Some("(") => {}
+                    next => {
+                        return Err(ParseError::at(
+                            input,
+                            next.unwrap_or(token),
+                            format!("expected '(' after {token}"),
+                        ));
+                    }
+                }
+                let body = parse_block(input, tokens, Some(token))?;
+                Command::Repeat { times, body }
+            }
+            _ => {
+                return Err(ParseError::at(
+                    input,
+                    token,
+                    format!("expected L, R, S or a repeat, got '{token}'"),
+                ));
+            }
+        };
+        commands.push(command);
+    }
+
+    match open {
+        Some(open) => Err(ParseError::at(input, open, "repeat is never closed")),
+        None => Ok(commands),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use aoc_core::Solution;
+
+    use crate::{Command, Counting, Day01, Dial, Program, parse_rotations};
+
+    const TEST_INPUT: &str = "L68\nL30\nR48\nL5\nR60\nL55\nL1\nL99\nR14\nL82";
+
+    fn parse(input: &str) -> Program {
+        input.parse().unwrap()
+    }
+
+    #[test]
+    fn it_should_run_plain_rotations_like_both_parts() {
+        let program = parse(TEST_INPUT);
+        assert_eq!(program.count(&Dial::part1()), 3);
+        assert_eq!(program.count(&Dial::part2()), 6);
+    }
+
+    #[test]
+    fn it_should_skip_comments_and_whitespace() {
+        let program = parse("# header\n\n  L68  \t\nL30 R48 # two on a line\r\n\n");
+        assert_eq!(
+            program,
+            Program {
+                commands: parse_rotations("L68\nL30\nR48")
+                    .unwrap()
+                    .into_iter()
+                    .map(Command::Rotate)
+                    .collect()
+            }
+        );
+    }
+
+    #[test]
+    fn it_should_unroll_repeats() {
+        let unrolled = parse("L10 R5 L10 R5 L10 R5 L10 R5 L10 R5 L10 R5 R3");
+        for dial in [Dial::part1().starting_at(10), Dial::part2().starting_at(10)] {
+            assert_eq!(
+                parse("2x(3x(L10 R5)) R3").run(&dial, 10),
+                unrolled.run(&dial, 10)
+            );
+        }
+        assert_eq!(parse("0x(R50)").count(&Dial::part2()), 0);
+    }
+
+    #[test]
+    fn it_should_set_without_hitting_anything() {
+        assert_eq!(parse("S0").run(&Dial::part2(), 50), (0, 0));
+        assert_eq!(parse("S250 R50").run(&Dial::part2(), 50), (0, 1));
+
+        let dial = Dial::new(10).targets([3]).counting(Counting::Passing);
+        assert_eq!(parse("S2\n4x(R1 S2)").count(&dial), 4);
+    }
+
+    #[test]
+    fn it_should_match_the_solution_on_puzzle_input() {
+        let rotations = parse_rotations(TEST_INPUT).unwrap();
+        let program = parse(&format!("{TEST_INPUT}\n"));
+        assert_eq!(
+            (program.count(&Dial::part1()), program.count(&Dial::part2())),
+            (Day01::part1(&rotations), Day01::part2(&rotations))
+        );
+    }
+
+    #[test]
+    fn it_should_report_bad_programs() {
+        let err = "L68\n3x(L10 X5)".parse::<Program>().unwrap_err();
+        assert_eq!((err.line, err.column, err.len), (2, 8, 2));
+
+        let err = "L68\n  3x(L10".parse::<Program>().unwrap_err();
+        assert_eq!(
+            (err.line, err.column, err.message.as_str()),
+            (2, 3, "repeat is never closed")
+        );
+
+        let err = "3x L10".parse::<Program>().unwrap_err();
+        assert_eq!(
+            (err.column, err.message.as_str()),
+            (4, "expected '(' after 3x")
+        );
+
+        let err = "R5)".parse::<Program>().unwrap_err();
+        assert_eq!(err.column, 3);
+
+        let err = "S-1".parse::<Program>().unwrap_err();
+        assert_eq!((err.column, err.len), (2, 2));
+
+        let err = "R12 34".parse::<Program>().unwrap_err();
+        assert_eq!((err.column, err.len), (5, 2));
+    }
+}
@@ -0,0 +1,179 @@
+use rayon::prelude::*;
+
+use crate::{Dial, Direction, Rotation};
+
+/// rotations handed to each rayon task, small chunks cost more combining than they save
+const CHUNK: usize = 4096;
+
+/// What a run of rotations does to a dial, whatever position it starts from
+///
+/// Summaries of consecutive runs combine with [`Summary::then`], and that's associative, so a
+/// long list of rotations can be summarised in pieces and the pieces combined in any grouping.
+///
+/// ```
+/// use day01::{Dial, Summary, parse_rotations};
+///
+/// let dial = Dial::part2();
+/// let rotations = parse_rotations("L68\nL30\nR48").unwrap();
+/// let (first, rest) = rotations.split_at(1);
+///
+/// let whole = Summary::of(&dial, first).then(&Summary::of(&dial, rest));
+/// assert_eq!(whole.hits_from(dial.start()), dial.count(&rotations));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Summary {
+    /// how far round the dial ends up, `0..positions`
+    offset: i64,
+    /// targets hit from each start position
+    hits: Vec<u64>,
+}
+
+impl Summary {
+    /// no rotations at all
+    pub fn identity(dial: &Dial) -> Self {
+        Self {
+            offset: 0,
+            hits: vec![0; dial.positions() as usize],
+        }
+    }
+
+    pub fn of(dial: &Dial, rotations: &[Rotation]) -> Self {
+        let mut summary = Self::identity(dial);
+        for rotation in rotations {
+            summary.push(dial, rotation);
+        }
+        summary
+    }
+
+    /// add one more rotation to the end
+    pub fn push(&mut self, dial: &Dial, rotation: &Rotation) {
+        let positions = self.positions();
+        for (start, hits) in self.hits.iter_mut().enumerate() {
+            let pos = (start as i64 + self.offset) % positions;
+            *hits += dial.turn(pos, rotation).1;
+        }
+
+        let distance = i64::from(rotation.distance);
+        self.offset = match rotation.direction {
+            Direction::Left => self.offset - distance,
+            Direction::Right => self.offset + distance,
+        }
+        .rem_euclid(positions);
+    }
+
+    /// `self` followed by `next`, both for the same dial
+    pub fn then(&self, next: &Summary) -> Summary {
+        let positions = self.positions();
+        Summary {
+            offset: (self.offset + next.offset) % positions,
+            hits: self
+                .hits
+                .iter()
+                .enumerate()
+                .map(|(start, hits)| hits + next.hits_from(start as i64 + self.offset))
+                .collect(),
+        }
+    }
+
+    pub fn end_from(&self, start: i64) -> i64 {
+        (start + self.offset).rem_euclid(self.positions())
+    }
+
+    pub fn hits_from(&self, start: i64) -> u64 {
+        self.hits[start.rem_euclid(self.positions()) as usize]
+    }
+
+    fn positions(&self) -> i64 {
+        self.hits.len() as i64
+    }
+}
+
+/// the same as [`Dial::count`], summarising chunks of `rotations` in parallel
+///
+/// Every chunk works out its hits from each of the dial's positions, so this only pays off
+/// for big inputs on small dials.
+pub fn count_parallel(dial: &Dial, rotations: &[Rotation]) -> u64 {
+    rotations
+        .par_chunks(CHUNK)
+        .map(|chunk| Summary::of(dial, chunk))
+        .reduce(|| Summary::identity(dial), |a, b| a.then(&b))
+        .hits_from(dial.start())
+}
+
+#[cfg(test)]
+mod test {
+    use proptest::prelude::*;
+
+    use crate::{
+        Counting, Dial, Direction, Rotation, calc_password, calc_password_with_clicks,
+        parse_rotations,
+        summary::{Summary, count_parallel},
+    };
+
+    fn rotation(left: bool, distance: i32) -> Rotation {
+        Rotation {
+            direction: if left {
+                Direction::Left
+            } else {
+                Direction::Right
+            },
+            distance,
+        }
+    }
+
+    #[test]
+    fn it_should_summarise_from_every_start() {
+        let dial = Dial::part2();
+        let summary = Summary::of(&dial, &parse_rotations("R60\nL5").unwrap());
+
+        assert_eq!(summary.end_from(50), 5);
+        assert_eq!(summary.hits_from(50), 1);
+        assert_eq!(summary.hits_from(0), 0);
+        assert_eq!(summary.hits_from(45), 2);
+    }
+
+    #[test]
+    fn it_should_count_huge_inputs_like_calc_password() {
+        let rotations: Vec<Rotation> = (0..50_000)
+            .map(|i| rotation(i % 3 == 0, i * 7 % 1000))
+            .collect();
+
+        assert_eq!(
+            count_parallel(&Dial::part1(), &rotations),
+            calc_password(50, &rotations)
+        );
+        assert_eq!(
+            count_parallel(&Dial::part2(), &rotations),
+            calc_password_with_clicks(50, &rotations)
+        );
+        assert_eq!(count_parallel(&Dial::part2(), &[]), 0);
+    }
+
+    proptest! {
+        #[test]
+        fn it_should_combine_associatively(
+            positions in 1..30u32,
+            targets in prop::collection::vec(0..30i64, 0..3),
+            passing in any::<bool>(),
+            turns in prop::collection::vec((any::<bool>(), 0..100i32), 0..30),
+            a in 0..30usize,
+            b in 0..30usize,
+        ) {
+            let dial = Dial::new(positions)
+                .targets(targets)
+                .counting(if passing { Counting::Passing } else { Counting::Landing });
+            let rotations: Vec<Rotation> =
+                turns.into_iter().map(|(left, distance)| rotation(left, distance)).collect();
+            let (a, b) = (a.min(rotations.len()), b.min(rotations.len()));
+            let (a, b) = (a.min(b), a.max(b));
+
+            let [x, y, z] = [&rotations[..a], &rotations[a..b], &rotations[b..]]
+                .map(|rotations| Summary::of(&dial, rotations));
+            let whole = Summary::of(&dial, &rotations);
+            prop_assert_eq!(&x.then(&y).then(&z), &whole);
+            prop_assert_eq!(&x.then(&y.then(&z)), &whole);
+            prop_assert_eq!(&Summary::identity(&dial).then(&whole), &whole);
+            prop_assert_eq!(whole.hits_from(dial.start()), dial.count(&rotations));
+        }
+    }
+}
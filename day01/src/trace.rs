@@ -0,0 +1,356 @@
+use std::{fmt, io::Write, path::Path};
+
+use aoc_core::{Error, ParseError, read_input};
+use serde::{Deserialize, Serialize};
+
+use crate::{Dial, Rotation, input_lines};
+
+const CSV_HEADER: &str = "line,source,start,end,hits,total";
+
+/// What one rotation did to the dial
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Step {
+    /// 1-based line in the input
+    pub line: usize,
+    /// the rotation as written, eg. `L68`
+    pub source: String,
+    pub start: i64,
+    pub end: i64,
+    /// targets hit by this rotation
+    pub hits: u64,
+    /// targets hit so far, including this rotation
+    pub total: u64,
+}
+
+/// Every rotation a dial made, for working out where two ways of counting disagree
+///
+/// ```
+/// use day01::{Dial, Trace};
+///
+/// let trace = Trace::record(&Dial::part2(), "L68\nL30").unwrap();
+/// assert_eq!(trace.total(), 1);
+/// assert!(trace.replay(&Dial::part2(), "L68\nL30").is_ok());
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Trace {
+    pub steps: Vec<Step>,
+}
+
+/// Why a trace doesn't match its input
+#[derive(Debug)]
+pub enum ReplayError {
+    /// the input itself is broken
+    Parse(ParseError),
+    /// the first step that differs, `None` when one side ran out of steps
+    Mismatch {
+        trace: Option<Box<Step>>,
+        replayed: Option<Box<Step>>,
+    },
+}
+
+impl fmt::Display for ReplayError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let describe = |step: &Option<Box<Step>>| match step {
+            Some(step) => format!(
+                "line {} {}: {} -> {}, {} hits, {} total",
+                step.line, step.source, step.start, step.end, step.hits, step.total
+            ),
+            None => "nothing".to_string(),
+        };
+
+        match self {
+            ReplayError::Parse(e) => write!(f, "{e}"),
+            ReplayError::Mismatch { trace, replayed } => write!(
+                f,
+                "trace has {} but the input gives {}",
+                describe(trace),
+                describe(replayed)
+            ),
+        }
+    }
+}
+
+impl std::error::Error for ReplayError {}
+
+impl Trace {
+    /// turn `dial` through every rotation in `input`, keeping track of each one
+    pub fn record(dial: &Dial, input: &str) -> Result<Self, ParseError> {
+        let mut pos = dial.start();
+        let mut total = 0;
+        let mut steps = vec![];
+
+        for (i, line) in input_lines(input).enumerate() {
+            let rotation = line
+                .parse::<Rotation>()
+                .map_err(|e| e.within(input, line))?;
+            let (end, hits) = dial.turn(pos, &rotation);
+            total += hits;
+
+            steps.push(Step {
+                line: i + 1,
+                source: line.to_string(),
+                start: pos,
+                end,
+                hits,
+                total,
+            });
+            pos = end;
+        }
+
+        Ok(Self { steps })
+    }
+
+    pub fn total(&self) -> u64 {
+        self.steps.last().map_or(0, |step| step.total)
+    }
+
+    /// check this trace is what `dial` does with `input`
+    pub fn replay(&self, dial: &Dial, input: &str) -> Result<(), ReplayError> {
+        let replayed = Self::record(dial, input).map_err(ReplayError::Parse)?;
+
+        for i in 0..self.steps.len().max(replayed.steps.len()) {
+            let (trace, replayed) = (self.steps.get(i), replayed.steps.get(i));
+            if trace != replayed {
+                return Err(ReplayError::Mismatch {
+                    trace: trace.cloned().map(Box::new),
+                    replayed: replayed.cloned().map(Box::new),
+                });
+            }
+        }
+
+        Ok(())
+    }
+
+    pub fn to_csv(&self) -> String {
+        let mut csv = format!("{CSV_HEADER}\n");
+        for step in &self.steps {
+            csv += &format!(
+                "{},{},{},{},{},{}\n",
+                step.line, step.source, step.start, step.end, step.hits, step.total
+            );
+        }
+        csv
+    }
+
+    pub fn from_csv(csv: &str) -> Result<Self, ParseError> {
+        let mut lines = csv.lines();
+        match lines.next() {
+            Some(CSV_HEADER) => {}
+            header => {
+                return Err(ParseError::at(
+                    csv,
+                    header.unwrap_or_default(),
+                    format!("expected a '{CSV_HEADER}' header"),
+                ));
+            }
+        }
+
+        let steps = lines
+            .map(|row| parse_row(row).map_err(|e| e.within(csv, row)))
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self { steps })
+    }
+
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(&self.steps).expect("steps always serialise")
+    }
+
+    pub fn from_json(json: &str) -> Result<Self, ParseError> {
+        let steps = serde_json::from_str(json).map_err(|e| {
+            let text = json
+                .lines()
+                .nth(e.line().saturating_sub(1))
+                .unwrap_or_default();
+            ParseError {
+                day: 0,
+                line: e.line(),
+                column: e.column().max(1),
+                len: 1,
+                text: text.to_string(),
+                message: e.to_string(),
+            }
+        })?;
+
+        Ok(Self { steps })
+    }
+}
+
+/// `--trace <csv|json>`: every rotation `dial` makes in `input`, exported as `format`
+pub fn write_trace(
+    out: &mut (impl Write + ?Sized),
+    dial: &Dial,
+    input: &str,
+    format: &str,
+) -> Result<(), Error> {
+    let trace = Trace::record(dial, input)?;
+    let exported = match format {
+        "csv" => trace.to_csv(),
+        "json" => trace.to_json() + "\n",
+        _ => {
+            return Err(Error::Usage(format!(
+                "traces are csv or json, not '{format}'"
+            )));
+        }
+    };
+    out.write_all(exported.as_bytes()).map_err(Error::stdout)
+}
+
+/// `--replay <trace file>`: check a CSV or JSON trace against what `dial` does with `input`,
+/// saying where they first differ if they do
+pub fn check_trace(
+    out: &mut (impl Write + ?Sized),
+    dial: &Dial,
+    input: &str,
+    path: &Path,
+) -> Result<bool, Error> {
+    let text = read_input(path)?;
+    let trace = match text.trim_start().starts_with('[') {
+        true => Trace::from_json(&text),
+        false => Trace::from_csv(&text),
+    }
+    .map_err(|e| ParseError {
+        message: format!("{}: {}", path.display(), e.message),
+        ..e
+    })?;
+
+    let (ok, result) = match trace.replay(dial, input) {
+        Ok(()) => (
+            true,
+            writeln!(
+                out,
+                "{} steps match, total={}",
+                trace.steps.len(),
+                trace.total()
+            ),
+        ),
+        Err(ReplayError::Parse(e)) => return Err(e.into()),
+        Err(e) => (false, writeln!(out, "{e}")),
+    };
+    result.map_err(Error::stdout)?;
+    Ok(ok)
+}
+
+fn parse_row(row: &str) -> Result<Step, ParseError> {
+    let fields: Vec<&str> = row.split(',').collect();
+    let [line, source, start, end, hits, total] = fields[..] else {
+        return Err(ParseError::at(
+            row,
+            row,
+            format!("expected 6 fields, got {}", fields.len()),
+        ));
+    };
+
+    fn number<T: std::str::FromStr>(row: &str, field: &str) -> Result<T, ParseError> {
+        field
+            .parse()
+            .map_err(|_| ParseError::at(row, field, format!("'{field}' isn't a number")))
+    }
+
+    Ok(Step {
+        line: number(row, line)?,
+        source: source.to_string(),
+        start: number(row, start)?,
+        end: number(row, end)?,
+        hits: number(row, hits)?,
+        total: number(row, total)?,
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use std::{env::temp_dir, fs, process};
+
+    use crate::{Dial, ReplayError, Trace, check_trace, write_trace};
+
+    const TEST_INPUT: &str = "L68\nL30\nR48\nL5\nR60\nL55\nL1\nL99\nR14\nL82";
+
+    #[test]
+    fn it_should_record_every_rotation() {
+        let trace = Trace::record(&Dial::part2(), TEST_INPUT).unwrap();
+
+        assert_eq!(trace.steps.len(), 10);
+        assert_eq!(trace.total(), 6);
+
+        let first = &trace.steps[0];
+        assert_eq!((first.line, first.source.as_str()), (1, "L68"));
+        assert_eq!((first.start, first.end, first.hits), (50, 82, 1));
+    }
+
+    #[test]
+    fn it_should_trace_whatever_the_solver_reads() {
+        let input = "L68 \nL30\r\n";
+        let trace = Trace::record(&Dial::part2(), input).unwrap();
+        assert_eq!(trace.steps[0].source, "L68");
+        assert!(trace.replay(&Dial::part2(), input).is_ok());
+    }
+
+    #[test]
+    fn it_should_round_trip_exports() {
+        let trace = Trace::record(&Dial::part1(), TEST_INPUT).unwrap();
+
+        let csv = trace.to_csv();
+        assert!(csv.starts_with("line,source,start,end,hits,total\n1,L68,50,82,0,0\n"));
+        assert_eq!(Trace::from_csv(&csv).unwrap(), trace);
+        assert_eq!(Trace::from_json(&trace.to_json()).unwrap(), trace);
+    }
+
+    #[test]
+    fn it_should_find_where_traces_disagree() {
+        let landing = Trace::record(&Dial::part1(), TEST_INPUT).unwrap();
+        assert!(landing.replay(&Dial::part1(), TEST_INPUT).is_ok());
+
+        let Err(ReplayError::Mismatch { trace, replayed }) =
+            landing.replay(&Dial::part2(), TEST_INPUT)
+        else {
+            panic!("part 2 should count differently");
+        };
+        // L68 from 50 passes 0 once, which only part 2 counts
+        assert_eq!(trace.unwrap().hits, 0);
+        assert_eq!(replayed.unwrap().hits, 1);
+
+        let short = Trace::record(&Dial::part1(), "L68").unwrap();
+        assert!(matches!(
+            short.replay(&Dial::part1(), TEST_INPUT),
+            Err(ReplayError::Mismatch { trace: None, .. })
+        ));
+    }
+
+    #[test]
+    fn it_should_check_trace_files() {
+        let path = temp_dir().join(format!("day01-trace-test-{}.csv", process::id()));
+
+        let mut csv = vec![];
+        write_trace(&mut csv, &Dial::part2(), TEST_INPUT, "csv").unwrap();
+        fs::write(&path, &csv).unwrap();
+
+        let mut out = vec![];
+        assert!(check_trace(&mut out, &Dial::part2(), TEST_INPUT, &path).unwrap());
+        assert_eq!(String::from_utf8(out).unwrap(), "10 steps match, total=6\n");
+
+        let mut out = vec![];
+        assert!(!check_trace(&mut out, &Dial::part1(), TEST_INPUT, &path).unwrap());
+        assert!(
+            String::from_utf8(out)
+                .unwrap()
+                .starts_with("trace has line 1 L68")
+        );
+
+        let mut json = vec![];
+        write_trace(&mut json, &Dial::part1(), TEST_INPUT, "json").unwrap();
+        fs::write(&path, &json).unwrap();
+        assert!(check_trace(&mut vec![], &Dial::part1(), TEST_INPUT, &path).unwrap());
+
+        assert!(write_trace(&mut vec![], &Dial::part1(), TEST_INPUT, "xml").is_err());
+        fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn it_should_report_bad_trace_files() {
+        let err = Trace::from_csv("line,source,start,end,hits,total\n1,L68,50,8x,0,0").unwrap_err();
+        assert_eq!((err.line, err.column, err.len), (2, 10, 2));
+
+        assert!(Trace::from_csv("1,L68,50,82,0,0").is_err());
+        assert_eq!(Trace::from_json("[{\"line\": 1}]").unwrap_err().line, 1);
+    }
+}
@@ -0,0 +1,343 @@
+use std::{
+    cmp::Ordering,
+    fmt,
+    iter::{Sum, successors},
+    ops::{Add, Mul},
+    str::FromStr,
+};
+
+use aoc_core::ParseError;
+
+/// A whole number with as many digits as it needs, so IDs and their sums never overflow
+///
+/// Stored as little-endian 32 bit limbs without any high zero limbs, so zero has none.
+///
+/// ```
+/// use day02::Id;
+///
+/// let id: Id = "123456789012345678901234567890".parse().unwrap();
+/// assert_eq!((&id + &id).to_string(), "246913578024691357802469135780");
+/// assert!(u64::try_from(&id).is_err());
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct Id {
+    limbs: Vec<u32>,
+}
+
+/// An [`Id`] too big for the type it was converted to
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Overflow(pub Id);
+
+impl fmt::Display for Overflow {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} doesn't fit in a u64", self.0)
+    }
+}
+
+impl std::error::Error for Overflow {}
+
+/// Space for [`Id::digits_into`] that's kept between calls
+#[derive(Debug, Clone, Default)]
+pub struct DigitBuffer {
+    digits: Vec<u32>,
+    /// what's left to divide
+    limbs: Vec<u32>,
+}
+
+impl Id {
+    pub fn zero() -> Self {
+        Self::default()
+    }
+
+    pub fn one() -> Self {
+        Self::from(1u64)
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.limbs.is_empty()
+    }
+
+    /// `base` to the power of `exp`
+    pub fn pow(base: u32, exp: u32) -> Self {
+        (0..exp).fold(Self::one(), |n, _| n.mul_small(base))
+    }
+
+    pub fn add_small(&self, n: u32) -> Self {
+        self + &Self::from(u64::from(n))
+    }
+
+    pub fn mul_small(&self, n: u32) -> Self {
+        let mut carry = 0u64;
+        let mut limbs: Vec<u32> = self
+            .limbs
+            .iter()
+            .map(|limb| {
+                let product = u64::from(*limb) * u64::from(n) + carry;
+                carry = product >> 32;
+                product as u32
+            })
+            .collect();
+        limbs.push(carry as u32);
+        Self::normalised(limbs)
+    }
+
+    /// the quotient and remainder
+    pub fn div_small(&self, n: u32) -> (Self, u32) {
+        let mut limbs = self.limbs.clone();
+        let rem = div_limbs(&mut limbs, n);
+        (Self::normalised(limbs), rem)
+    }
+
+    /// `None` instead of going below zero
+    pub fn checked_sub(&self, other: &Id) -> Option<Self> {
+        if *self < *other {
+            return None;
+        }
+
+        let mut borrow = 0i64;
+        let limbs = self
+            .limbs
+            .iter()
+            .enumerate()
+            .map(|(i, limb)| {
+                let mut diff =
+                    i64::from(*limb) - i64::from(other.limbs.get(i).copied().unwrap_or(0)) - borrow;
+                borrow = i64::from(diff < 0);
+                if diff < 0 {
+                    diff += 1 << 32;
+                }
+                diff as u32
+            })
+            .collect();
+        Some(Self::normalised(limbs))
+    }
+
+    /// digits in `base`, most significant first, and a single 0 for zero
+    pub fn digits(&self, base: u32) -> Vec<u32> {
+        self.digits_into(base, &mut DigitBuffer::default()).to_vec()
+    }
+
+    /// [`Id::digits`] without allocating once `buffer` is big enough
+    pub fn digits_into<'a>(&self, base: u32, buffer: &'a mut DigitBuffer) -> &'a [u32] {
+        let DigitBuffer { digits, limbs } = buffer;
+        digits.clear();
+        limbs.clone_from(&self.limbs);
+        loop {
+            digits.push(div_limbs(limbs, base));
+            if limbs.is_empty() {
+                break;
+            }
+        }
+        digits.reverse();
+        digits
+    }
+
+    /// the number written as `digits` in `base`, most significant first
+    pub fn from_digits(digits: &[u32], base: u32) -> Self {
+        digits
+            .iter()
+            .fold(Self::zero(), |n, digit| n.mul_small(base).add_small(*digit))
+    }
+
+    /// every number from `self` to `last`, inclusive
+    pub fn up_to(self, last: Id) -> impl Iterator<Item = Id> {
+        successors(Some(self), |n| Some(n.add_small(1))).take_while(move |n| *n <= last)
+    }
+
+    fn normalised(mut limbs: Vec<u32>) -> Self {
+        while limbs.last() == Some(&0) {
+            limbs.pop();
+        }
+        Self { limbs }
+    }
+}
+
+/// divide little-endian `limbs` by `n` in place, dropping high zero limbs, and return the remainder
+fn div_limbs(limbs: &mut Vec<u32>, n: u32) -> u32 {
+    assert!(n > 0, "can't divide by zero");
+
+    let mut rem = 0u64;
+    for limb in limbs.iter_mut().rev() {
+        let current = (rem << 32) | u64::from(*limb);
+        *limb = (current / u64::from(n)) as u32;
+        rem = current % u64::from(n);
+    }
+    while limbs.last() == Some(&0) {
+        limbs.pop();
+    }
+    rem as u32
+}
+
+impl From<u64> for Id {
+    fn from(n: u64) -> Self {
+        Self::normalised(vec![n as u32, (n >> 32) as u32])
+    }
+}
+
+impl TryFrom<&Id> for u64 {
+    type Error = Overflow;
+
+    fn try_from(id: &Id) -> Result<Self, Self::Error> {
+        match id.limbs[..] {
+            [] => Ok(0),
+            [low] => Ok(u64::from(low)),
+            [low, high] => Ok(u64::from(high) << 32 | u64::from(low)),
+            _ => Err(Overflow(id.clone())),
+        }
+    }
+}
+
+impl Ord for Id {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.limbs
+            .len()
+            .cmp(&other.limbs.len())
+            .then_with(|| self.limbs.iter().rev().cmp(other.limbs.iter().rev()))
+    }
+}
+
+impl PartialOrd for Id {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Add for &Id {
+    type Output = Id;
+
+    fn add(self, other: &Id) -> Id {
+        let mut carry = 0u64;
+        let mut limbs: Vec<u32> = (0..self.limbs.len().max(other.limbs.len()))
+            .map(|i| {
+                let sum = u64::from(self.limbs.get(i).copied().unwrap_or(0))
+                    + u64::from(other.limbs.get(i).copied().unwrap_or(0))
+                    + carry;
+                carry = sum >> 32;
+                sum as u32
+            })
+            .collect();
+        limbs.push(carry as u32);
+        Id::normalised(limbs)
+    }
+}
+
+impl Mul for &Id {
+    type Output = Id;
+
+    fn mul(self, other: &Id) -> Id {
+        let mut limbs = vec![0u32; self.limbs.len() + other.limbs.len()];
+        for (i, a) in self.limbs.iter().enumerate() {
+            let mut carry = 0u64;
+            for (j, b) in other.limbs.iter().enumerate() {
+                let product = u64::from(*a) * u64::from(*b) + u64::from(limbs[i + j]) + carry;
+                limbs[i + j] = product as u32;
+                carry = product >> 32;
+            }
+            limbs[i + other.limbs.len()] = carry as u32;
+        }
+        Id::normalised(limbs)
+    }
+}
+
+impl Sum for Id {
+    fn sum<I: Iterator<Item = Id>>(iter: I) -> Self {
+        iter.fold(Id::zero(), |total, n| &total + &n)
+    }
+}
+
+impl fmt::Display for Id {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let digits: String = self
+            .digits(10)
+            .iter()
+            .map(|digit| char::from_digit(*digit, 10).expect("decimal digit"))
+            .collect();
+        f.pad(&digits)
+    }
+}
+
+impl FromStr for Id {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.is_empty() || !s.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(ParseError::at(s, s, format!("'{s}' isn't an ID")));
+        }
+
+        let digits: Vec<u32> = s.bytes().map(|b| u32::from(b - b'0')).collect();
+        Ok(Self::from_digits(&digits, 10))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use proptest::prelude::*;
+
+    use crate::{DigitBuffer, Id, Overflow};
+
+    fn id(n: u128) -> Id {
+        n.to_string().parse().unwrap()
+    }
+
+    #[test]
+    fn it_should_parse_and_print() {
+        assert_eq!(id(0).to_string(), "0");
+        assert_eq!("007".parse::<Id>().unwrap(), Id::from(7));
+
+        let huge = "340282366920938463463374607431768211456123";
+        assert_eq!(huge.parse::<Id>().unwrap().to_string(), huge);
+
+        let err = "12a".parse::<Id>().unwrap_err();
+        assert_eq!(err.message, "'12a' isn't an ID");
+        assert!("".parse::<Id>().is_err());
+    }
+
+    #[test]
+    fn it_should_report_overflow() {
+        assert_eq!(u64::try_from(&Id::from(u64::MAX)), Ok(u64::MAX));
+
+        let too_big = Id::from(u64::MAX).add_small(1);
+        assert_eq!(u64::try_from(&too_big), Err(Overflow(too_big.clone())));
+        assert_eq!(Id::one().checked_sub(&too_big), None);
+    }
+
+    #[test]
+    fn it_should_convert_bases() {
+        assert_eq!(Id::from(10).digits(2), [1, 0, 1, 0]);
+        assert_eq!(Id::zero().digits(10), [0]);
+        assert_eq!(Id::from_digits(&[1, 0, 1, 0], 2), Id::from(10));
+        assert_eq!(Id::pow(10, 20).to_string(), "100000000000000000000");
+    }
+
+    #[test]
+    fn it_should_reuse_its_digit_buffer() {
+        let mut buffer = DigitBuffer::default();
+        assert_eq!(
+            id(u128::MAX).digits_into(10, &mut buffer),
+            id(u128::MAX).digits(10)
+        );
+        let capacity = (buffer.digits.capacity(), buffer.limbs.capacity());
+
+        for n in 0..1000 {
+            assert_eq!(
+                Id::from(n).digits_into(7, &mut buffer),
+                Id::from(n).digits(7)
+            );
+        }
+        assert_eq!(
+            (buffer.digits.capacity(), buffer.limbs.capacity()),
+            capacity
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn it_should_do_arithmetic_like_u128(a in 0..u64::MAX as u128, b in 0..u64::MAX as u128) {
+            prop_assert_eq!(&id(a) + &id(b), id(a + b));
+            prop_assert_eq!(&id(a) * &id(b), id(a * b));
+            prop_assert_eq!(id(a).checked_sub(&id(b)), a.checked_sub(b).map(id));
+            prop_assert_eq!(id(a).cmp(&id(b)), a.cmp(&b));
+            prop_assert_eq!(id(a).div_small(7), (id(a / 7), (a % 7) as u32));
+        }
+    }
+}
@@ -0,0 +1,143 @@
+use std::{fmt, str::FromStr};
+
+use aoc_core::{ParseError, Solution};
+
+mod id;
+mod list;
+mod ranges;
+mod repeats;
+
+pub use id::{DigitBuffer, Id, Overflow};
+pub use list::{InvalidId, invalid_ids, subtotals, write_list};
+pub use ranges::IdRanges;
+pub use repeats::{Repetition, RepetitionRule, Scratch};
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    const DAY: u8 = 2;
+
+    type Input<'a> = IdRanges;
+    type Part1 = Id;
+    type Part2 = Id;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        process_input(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Self::Part1 {
+        calc_ids(input.ranges())
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Self::Part2 {
+        calc_ids_repeated(input.ranges())
+    }
+}
+
+/// sum of every ID made of a block repeated twice
+pub fn calc_ids(ranges: &[IdRange]) -> Id {
+    sum_invalid(ranges, &RepetitionRule::exactly(2))
+}
+
+/// sum of every ID made of a block repeated at least twice
+pub fn calc_ids_repeated(ranges: &[IdRange]) -> Id {
+    sum_invalid(ranges, &RepetitionRule::at_least(2))
+}
+
+/// sum of every ID in `ranges` that `rule` says is invalid
+/// never overflows, `u64::try_from` checks whether it fits
+pub fn sum_invalid(ranges: &[IdRange], rule: &RepetitionRule) -> Id {
+    ranges.iter().map(|range| rule.sum(range)).sum()
+}
+
+/// An inclusive range of IDs, eg. `11-22`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IdRange {
+    pub start: Id,
+    pub end: Id,
+}
+
+impl IdRange {
+    pub fn new(start: impl Into<Id>, end: impl Into<Id>) -> Self {
+        Self {
+            start: start.into(),
+            end: end.into(),
+        }
+    }
+}
+
+impl fmt::Display for IdRange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}-{}", self.start, self.end)
+    }
+}
+
+impl FromStr for IdRange {
+    type Err = ParseError;
+
+    fn from_str(range: &str) -> Result<Self, Self::Err> {
+        let (a, b) = range
+            .split_once("-")
+            .ok_or_else(|| ParseError::at(range, range, "expected a range like 11-22"))?;
+        let num = |n: &str| n.parse::<Id>().map_err(|e| e.within(range, n));
+
+        let (start, end) = (num(a)?, num(b)?);
+        if end < start {
+            return Err(ParseError::at(
+                range,
+                range,
+                format!("range {range} ends before it starts"),
+            ));
+        }
+
+        Ok(IdRange { start, end })
+    }
+}
+
+/// comma separated ranges, ignoring whitespace and empty entries, merged so none overlap
+pub fn process_input(input: &str) -> Result<IdRanges, ParseError> {
+    let ranges = input
+        .split(",")
+        .map(str::trim)
+        .filter(|range| !range.is_empty())
+        .map(|range| range.parse::<IdRange>().map_err(|e| e.within(input, range)))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(IdRanges::new(ranges))
+}
+
+#[cfg(test)]
+mod test {
+    use aoc_core::Solution;
+
+    use crate::{Day02, Id, process_input};
+
+    aoc_core::example_files!(Day02);
+
+    #[test]
+    fn it_should_report_bad_ranges() {
+        let err = process_input("11-22,95-1x5").unwrap_err();
+        assert_eq!((err.line, err.column, err.len), (1, 10, 3));
+        assert_eq!(err.message, "'1x5' isn't an ID");
+
+        let err = process_input("11-22,95").unwrap_err();
+        assert_eq!((err.column, err.len), (7, 2));
+
+        let err = process_input("11-22, 120-95").unwrap_err();
+        assert_eq!((err.column, err.len), (8, 6));
+        assert_eq!(err.message, "range 120-95 ends before it starts");
+    }
+
+    #[test]
+    fn it_should_tidy_up_ranges() {
+        let ranges = process_input(" 11-22 ,\n95-115,100-120,11-22,\n").unwrap();
+        assert_eq!(
+            ranges.ranges(),
+            process_input("11-22,95-120").unwrap().ranges()
+        );
+        assert_eq!(ranges.double_covered(), &Id::from(12 + 16));
+
+        // 99 and 111 only count once each
+        assert_eq!(Day02::part2(&ranges), Id::from(11 + 22 + 99 + 111));
+    }
+}
@@ -0,0 +1,188 @@
+use std::io::{self, Write};
+
+use crate::{Id, IdRange, IdRanges, RepetitionRule, Scratch};
+
+/// One ID a rule says is invalid, and why
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InvalidId {
+    pub id: Id,
+    /// the range it was found in
+    pub range: IdRange,
+    /// digits in the repeated block, see [`RepetitionRule::period`]
+    pub period: u32,
+    /// how many times the block is repeated
+    pub repeats: u32,
+}
+
+/// every invalid ID in `ranges`, in range order and smallest first within each range
+///
+/// IDs are only worked out as they're taken, so even a range of every `u64` is fine.
+///
+/// ```
+/// use day02::{Id, IdRange, RepetitionRule, invalid_ids};
+///
+/// let ranges = [IdRange::new(95, 115)];
+/// let ids: Vec<Id> = invalid_ids(&ranges, &RepetitionRule::at_least(2)).map(|invalid| invalid.id).collect();
+/// assert_eq!(ids, [Id::from(99), Id::from(111)]);
+/// ```
+pub fn invalid_ids<'a>(
+    ranges: &'a [IdRange],
+    rule: &'a RepetitionRule,
+) -> impl Iterator<Item = InvalidId> + 'a {
+    let mut scratch = Scratch::default();
+    ranges
+        .iter()
+        .flat_map(move |range| {
+            rule.lengths(range)
+                .flat_map(move |len| rule.candidates(range, len).map(move |id| (range, len, id)))
+        })
+        .filter_map(move |(range, len, id)| {
+            let period = rule.period_with(&id, &mut scratch)?;
+            Some(InvalidId {
+                id,
+                range: range.clone(),
+                period,
+                repeats: len / period,
+            })
+        })
+}
+
+/// the sum of the invalid IDs in each range
+pub fn subtotals<'a>(
+    ranges: &'a [IdRange],
+    rule: &'a RepetitionRule,
+) -> impl Iterator<Item = (&'a IdRange, Id)> + 'a {
+    ranges.iter().map(|range| (range, rule.sum(range)))
+}
+
+/// a line per invalid ID, then each range's subtotal, how many IDs were in more than one of
+/// the ranges before they were merged and the overall total, eg.
+///
+/// ```text
+/// 95-120 99 period=1 repeats=2
+/// 95-120 111 period=1 repeats=3
+/// 95-120 subtotal=210
+/// double_covered=16
+/// total=210
+/// ```
+pub fn write_list(
+    out: &mut (impl Write + ?Sized),
+    ranges: &IdRanges,
+    rule: &RepetitionRule,
+) -> io::Result<()> {
+    let mut ids = invalid_ids(ranges.ranges(), rule).peekable();
+    let mut total = Id::zero();
+
+    for (range, subtotal) in subtotals(ranges.ranges(), rule) {
+        while let Some(invalid) = ids.next_if(|invalid| invalid.range == *range) {
+            writeln!(
+                out,
+                "{range} {} period={} repeats={}",
+                invalid.id, invalid.period, invalid.repeats
+            )?;
+        }
+        writeln!(out, "{range} subtotal={subtotal}")?;
+        total = &total + &subtotal;
+    }
+
+    writeln!(out, "double_covered={}", ranges.double_covered())?;
+    writeln!(out, "total={total}")
+}
+
+#[cfg(test)]
+mod test {
+    use aoc_core::Example;
+
+    use crate::{
+        Id, IdRange, InvalidId, RepetitionRule, invalid_ids, list::write_list, process_input,
+        subtotals,
+    };
+
+    #[test]
+    fn it_should_list_invalid_ids() {
+        let ranges = process_input("11-22,95-115,998-1012").unwrap();
+        let ids: Vec<InvalidId> =
+            invalid_ids(ranges.ranges(), &RepetitionRule::at_least(2)).collect();
+
+        let found: Vec<(u64, u32, u32)> = ids
+            .iter()
+            .map(|invalid| {
+                let id = u64::try_from(&invalid.id).unwrap();
+                (id, invalid.period, invalid.repeats)
+            })
+            .collect();
+        assert_eq!(
+            found,
+            [
+                (11, 1, 2),
+                (22, 1, 2),
+                (99, 1, 2),
+                (111, 1, 3),
+                (999, 1, 3),
+                (1010, 2, 2)
+            ]
+        );
+        assert_eq!(ids[3].range, IdRange::new(95, 115));
+    }
+
+    #[test]
+    fn it_should_list_huge_ranges_lazily() {
+        let everything = [IdRange::new(0, u64::MAX)];
+        let first = |rule: RepetitionRule| -> Vec<Id> {
+            invalid_ids(&everything, &rule)
+                .take(3)
+                .map(|invalid| invalid.id)
+                .collect()
+        };
+
+        assert_eq!(
+            first(RepetitionRule::exactly(2)),
+            [Id::from(11), Id::from(22), Id::from(33)]
+        );
+        assert_eq!(
+            first(RepetitionRule::palindromes()),
+            [Id::from(1), Id::from(2), Id::from(3)]
+        );
+    }
+
+    #[test]
+    fn it_should_add_up_like_the_sums() {
+        let example = Example::load(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/../examples/day02/example.txt"
+        ))
+        .unwrap();
+        let ranges = process_input(&example.input).unwrap();
+
+        for rule in [
+            RepetitionRule::exactly(2),
+            RepetitionRule::at_least(2),
+            RepetitionRule::periods([1, 3]),
+            RepetitionRule::palindromes(),
+        ] {
+            for (range, subtotal) in subtotals(ranges.ranges(), &rule) {
+                let listed: Id = invalid_ids(std::slice::from_ref(range), &rule)
+                    .map(|invalid| invalid.id)
+                    .sum();
+                assert_eq!(listed, subtotal, "{range} with {rule:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn it_should_write_a_list() {
+        let ranges = process_input("95-115,1-9,100-120").unwrap();
+        let mut out = vec![];
+        write_list(&mut out, &ranges, &RepetitionRule::at_least(2)).unwrap();
+
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "1-9 subtotal=0\n\
+             95-120 99 period=1 repeats=2\n\
+             95-120 111 period=1 repeats=3\n\
+             95-120 subtotal=210\n\
+             double_covered=16\n\
+             total=210\n"
+        );
+    }
+}
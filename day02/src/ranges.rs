@@ -0,0 +1,100 @@
+use crate::{Id, IdRange};
+
+/// ID ranges sorted and merged, so no ID gets counted twice
+///
+/// ```
+/// use day02::{Id, IdRange, IdRanges};
+///
+/// let ranges = IdRanges::new([IdRange::new(95, 115), IdRange::new(100, 120)]);
+/// assert_eq!(ranges.ranges(), [IdRange::new(95, 120)]);
+/// assert_eq!(ranges.double_covered(), &Id::from(16));
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct IdRanges {
+    ranges: Vec<IdRange>,
+    double_covered: Id,
+}
+
+impl IdRanges {
+    pub fn new(ranges: impl IntoIterator<Item = IdRange>) -> Self {
+        let mut sorted: Vec<IdRange> = ranges.into_iter().collect();
+        sorted.sort_unstable_by(|a, b| (&a.start, &a.end).cmp(&(&b.start, &b.end)));
+
+        let mut ranges: Vec<IdRange> = vec![];
+        let mut double_covered = Id::zero();
+        // everything before this is already counted as double covered
+        let mut uncounted = Id::zero();
+
+        for range in sorted {
+            if let Some(last) = ranges.last_mut() {
+                if range.start <= last.end {
+                    let from = (&range.start).max(&uncounted);
+                    let to = (&range.end).min(&last.end).clone();
+                    if let Some(overlap) = to.checked_sub(from) {
+                        double_covered = &double_covered + &overlap.add_small(1);
+                        uncounted = to.add_small(1);
+                    }
+                    if range.end > last.end {
+                        last.end = range.end;
+                    }
+                    continue;
+                }
+                if last.end.add_small(1) == range.start {
+                    last.end = range.end;
+                    continue;
+                }
+            }
+            ranges.push(range);
+        }
+
+        Self {
+            ranges,
+            double_covered,
+        }
+    }
+
+    /// sorted and not touching each other
+    pub fn ranges(&self) -> &[IdRange] {
+        &self.ranges
+    }
+
+    /// how many IDs were in more than one of the original ranges
+    pub fn double_covered(&self) -> &Id {
+        &self.double_covered
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{Id, IdRange, IdRanges};
+
+    fn ranges(ranges: &[(u64, u64)]) -> Vec<IdRange> {
+        ranges
+            .iter()
+            .map(|&(start, end)| IdRange::new(start, end))
+            .collect()
+    }
+
+    #[test]
+    fn it_should_merge_overlaps() {
+        let merged = IdRanges::new(ranges(&[(95, 115), (11, 22), (100, 120), (11, 22)]));
+        assert_eq!(merged.ranges(), ranges(&[(11, 22), (95, 120)]));
+        // 11-22 twice and 100-115
+        assert_eq!(merged.double_covered(), &Id::from(12 + 16));
+    }
+
+    #[test]
+    fn it_should_join_neighbours_without_double_covering() {
+        let merged = IdRanges::new(ranges(&[(1, 5), (6, 10), (12, 12)]));
+        assert_eq!(merged.ranges(), ranges(&[(1, 10), (12, 12)]));
+        assert_eq!(merged.double_covered(), &Id::from(0));
+    }
+
+    #[test]
+    fn it_should_count_ids_covered_many_times_once() {
+        let merged = IdRanges::new(ranges(&[(1, 10), (2, 8), (3, 4), (7, 12)]));
+        assert_eq!(merged.ranges(), ranges(&[(1, 12)]));
+        // 2-10
+        assert_eq!(merged.double_covered(), &Id::from(9));
+    }
+}
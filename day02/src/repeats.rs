@@ -0,0 +1,601 @@
+use std::{collections::BTreeSet, iter, ops::RangeInclusive};
+
+use aoc_core::period::Periods;
+
+use crate::{DigitBuffer, Id, IdRange};
+
+/// What makes an ID invalid
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Repetition {
+    /// one block repeated exactly this many times, eg. 2 for 123123 (and 1111 as 11 twice)
+    Exactly(u32),
+    /// one block repeated this many times or more
+    AtLeast(u32),
+    /// a block with one of these lengths repeated at least twice
+    Periods(BTreeSet<u32>),
+    /// reads the same backwards, eg. 12321
+    Palindrome,
+}
+
+/// A [`Repetition`] in some number base, 10 unless [`RepetitionRule::in_base`] says otherwise
+///
+/// Part 1 is [`RepetitionRule::exactly`] 2 and part 2 [`RepetitionRule::at_least`] 2:
+///
+/// ```
+/// use day02::{Id, IdRange, RepetitionRule};
+///
+/// let range = IdRange::new(95, 115);
+/// assert_eq!(RepetitionRule::exactly(2).sum(&range), Id::from(99));
+/// assert_eq!(RepetitionRule::at_least(2).sum(&range), Id::from(99 + 111));
+/// assert_eq!(RepetitionRule::palindromes().sum(&range), Id::from(99 + 101 + 111));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RepetitionRule {
+    repetition: Repetition,
+    base: u32,
+}
+
+/// Space reused from one ID to the next, so checking lots of them doesn't allocate
+#[derive(Debug, Clone, Default)]
+pub struct Scratch {
+    periods: Periods,
+    digits: DigitBuffer,
+}
+
+impl RepetitionRule {
+    pub fn new(repetition: Repetition) -> Self {
+        if let Repetition::Exactly(0) | Repetition::AtLeast(0) = repetition {
+            panic!("a block has to be repeated at least once");
+        }
+
+        Self {
+            repetition,
+            base: 10,
+        }
+    }
+
+    pub fn exactly(times: u32) -> Self {
+        Self::new(Repetition::Exactly(times))
+    }
+
+    pub fn at_least(times: u32) -> Self {
+        Self::new(Repetition::AtLeast(times))
+    }
+
+    pub fn periods(periods: impl IntoIterator<Item = u32>) -> Self {
+        Self::new(Repetition::Periods(periods.into_iter().collect()))
+    }
+
+    pub fn palindromes() -> Self {
+        Self::new(Repetition::Palindrome)
+    }
+
+    pub fn in_base(mut self, base: u32) -> Self {
+        assert!(
+            (2..=36).contains(&base),
+            "base {base} isn't between 2 and 36"
+        );
+        self.base = base;
+        self
+    }
+
+    pub fn repetition(&self) -> &Repetition {
+        &self.repetition
+    }
+
+    pub fn base(&self) -> u32 {
+        self.base
+    }
+
+    /// check a single ID
+    pub fn matches(&self, id: &Id) -> bool {
+        self.period(id).is_some()
+    }
+
+    /// the shortest repeated block that makes `id` invalid, how many digits a palindrome has
+    pub fn period(&self, id: &Id) -> Option<u32> {
+        self.period_with(id, &mut Scratch::default())
+    }
+
+    /// [`RepetitionRule::period`] reusing `scratch`, for checking lots of IDs
+    pub fn period_with(&self, id: &Id, scratch: &mut Scratch) -> Option<u32> {
+        let digits = id.digits_into(self.base, &mut scratch.digits);
+        let len = digits.len() as u32;
+
+        match self.repetition {
+            Repetition::Palindrome => digits.iter().eq(digits.iter().rev()).then_some(len),
+            _ => (1..=len)
+                .filter(|period| self.allows(len, *period))
+                .find(|period| scratch.periods.is_power(digits, (len / period) as usize)),
+        }
+    }
+
+    /// sum of every matching ID in `range`
+    ///
+    /// Both repeated blocks and palindromes are summed in closed form, so this only depends on
+    /// how many digits the IDs have.
+    pub fn sum(&self, range: &IdRange) -> Id {
+        self.lengths(range)
+            .map(|len| match self.repetition {
+                Repetition::Palindrome => self.sum_palindromes(range, len),
+                _ => self.sum_repeated(range, len),
+            })
+            .sum()
+    }
+
+    /// every `len` digit ID in `range` that could be invalid, smallest first
+    ///
+    /// Only IDs with an allowed period (or palindromes) are looked at, and only as they're
+    /// asked for, so this costs as much as the candidates taken rather than the range's width.
+    pub(crate) fn candidates(&self, range: &IdRange, len: u32) -> Box<dyn Iterator<Item = Id>> {
+        let Some((lo, hi)) = self.clamp(range, len) else {
+            return Box::new(iter::empty());
+        };
+        let base = self.base;
+
+        // palindromes are fixed by their first half, and bigger halves make bigger palindromes
+        if self.repetition == Repetition::Palindrome {
+            let half = len.div_ceil(2) as usize;
+            let Some((first, last)) = self.blocks(&lo, &hi, half, |half| mirror(half, len)) else {
+                return Box::new(iter::empty());
+            };
+
+            let mut digits = DigitBuffer::default();
+            let mut mirrored = vec![];
+            return Box::new(first.up_to(last).map(move |half| {
+                mirrored.clear();
+                mirrored.extend_from_slice(half.digits_into(base, &mut digits));
+                mirror_into(&mut mirrored, len);
+                Id::from_digits(&mirrored, base)
+            }));
+        }
+
+        let mut periods: Vec<_> = (1..=len)
+            .filter(|period| self.allows(len, *period))
+            .filter_map(|period| {
+                let multiplier = self.multiplier(len, period);
+                let (first, last) = self.periodic_blocks(&lo, &hi, len, period)?;
+                Some(
+                    first
+                        .up_to(last)
+                        .map(move |block| &block * &multiplier)
+                        .peekable(),
+                )
+            })
+            .collect();
+
+        // each period's IDs are in order, so merge them, skipping IDs that turn up for more
+        // than one period
+        Box::new(iter::from_fn(move || {
+            let next = periods
+                .iter_mut()
+                .filter_map(|ids| ids.peek())
+                .min()?
+                .clone();
+            for ids in &mut periods {
+                ids.next_if_eq(&next);
+            }
+            Some(next)
+        }))
+    }
+
+    /// how many digits the IDs in `range` can have
+    pub(crate) fn lengths(&self, range: &IdRange) -> RangeInclusive<u32> {
+        range.start.digits(self.base).len() as u32..=range.end.digits(self.base).len() as u32
+    }
+
+    /// whether a `len` digit ID made of a `period` digit block is invalid
+    fn allows(&self, len: u32, period: u32) -> bool {
+        if !len.is_multiple_of(period) {
+            return false;
+        }
+
+        match &self.repetition {
+            Repetition::Exactly(times) => len / period == *times,
+            Repetition::AtLeast(times) => len / period >= *times,
+            Repetition::Periods(periods) => period < len && periods.contains(&period),
+            Repetition::Palindrome => false,
+        }
+    }
+
+    /// the `len` digit IDs with any allowed period
+    ///
+    /// An ID with periods d and e dividing len also has period gcd(d, e), so an ID counts
+    /// when its smallest period divides an allowed one. Möbius inversion over the divisors
+    /// of len turns that into a signed sum over single periods.
+    fn sum_repeated(&self, range: &IdRange, len: u32) -> Id {
+        let Some((lo, hi)) = self.clamp(range, len) else {
+            return Id::zero();
+        };
+        let divisors: Vec<u32> = (1..=len).filter(|d| len.is_multiple_of(*d)).collect();
+        let covered = |m: u32| {
+            divisors
+                .iter()
+                .any(|d| d.is_multiple_of(m) && self.allows(len, *d))
+        };
+
+        let (mut added, mut taken) = (Id::zero(), Id::zero());
+        for &period in &divisors {
+            let weight: i32 = divisors
+                .iter()
+                .filter(|m| m.is_multiple_of(period) && covered(**m))
+                .map(|m| mobius(m / period))
+                .sum();
+            if weight == 0 {
+                continue;
+            }
+
+            let sum = self
+                .sum_with_period(&lo, &hi, len, period)
+                .mul_small(weight.unsigned_abs());
+            if weight > 0 {
+                added = &added + &sum;
+            } else {
+                taken = &taken + &sum;
+            }
+        }
+        added
+            .checked_sub(&taken)
+            .expect("every ID is counted at least as often as it's taken away")
+    }
+
+    /// the `len` digit palindromes in `range`
+    ///
+    /// A palindrome is its first half with that mirrored after it, so each digit of the half is
+    /// worth its place in both. Adding up each digit over the run of halves is then enough.
+    fn sum_palindromes(&self, range: &IdRange, len: u32) -> Id {
+        let Some((lo, hi)) = self.clamp(range, len) else {
+            return Id::zero();
+        };
+        let half = len.div_ceil(2);
+        let Some((first, last)) = self.blocks(&lo, &hi, half as usize, |half| mirror(half, len))
+        else {
+            return Id::zero();
+        };
+        let before = first
+            .checked_sub(&Id::one())
+            .expect("halves don't start with a 0");
+
+        (0..half)
+            .map(|i| {
+                // the i-th digit from the left, which ends up i digits from the right as well
+                let place = half - 1 - i;
+                let digits = self
+                    .digit_sum(&last, place)
+                    .checked_sub(&self.digit_sum(&before, place))
+                    .expect("more numbers have at least as big a digit sum");
+
+                let mut worth = Id::pow(self.base, len - 1 - i);
+                if i != len - 1 - i {
+                    worth = &worth + &Id::pow(self.base, i);
+                }
+                &digits * &worth
+            })
+            .sum()
+    }
+
+    /// the sum of the digits `place` from the right in every number from 0 to `n`
+    ///
+    /// That digit counts up through 0 to base - 1 once for every number made of the digits
+    /// above it, then gets as far as its digit in `n`.
+    fn digit_sum(&self, n: &Id, place: u32) -> Id {
+        let digits = n.digits(self.base);
+        let Some(at) = digits.len().checked_sub(place as usize + 1) else {
+            return Id::zero();
+        };
+        let high = Id::from_digits(&digits[..at], self.base);
+        let digit = digits[at];
+        let low = Id::from_digits(&digits[at + 1..], self.base);
+        let width = Id::pow(self.base, place);
+
+        let cycles = (&high * &width).mul_small(self.base * (self.base - 1) / 2);
+        let partial = width.mul_small(digit * digit.saturating_sub(1) / 2);
+        let last = low.add_small(1).mul_small(digit);
+        &(&cycles + &partial) + &last
+    }
+
+    /// sum of the IDs in `lo..=hi` (both `len` digits) that are a `period` digit block repeated
+    ///
+    /// Those are `block * multiplier`, eg. 123123 is 123 * 1001, so they're an arithmetic series.
+    fn sum_with_period(&self, lo: &[u32], hi: &[u32], len: u32, period: u32) -> Id {
+        let Some((first, last)) = self.periodic_blocks(lo, hi, len, period) else {
+            return Id::zero();
+        };
+
+        let count = last
+            .checked_sub(&first)
+            .expect("first block comes before the last")
+            .add_small(1);
+        let (sum, _) = (&(&first + &last) * &count).div_small(2);
+        &sum * &self.multiplier(len, period)
+    }
+
+    /// the `period` digit blocks that repeat into an ID in `lo..=hi`
+    fn periodic_blocks(&self, lo: &[u32], hi: &[u32], len: u32, period: u32) -> Option<(Id, Id)> {
+        let repeat = |block: &[u32]| block.repeat((len / period) as usize);
+        self.blocks(lo, hi, period as usize, repeat)
+    }
+
+    /// the first and last blocks of `size` digits that `expand` makes into an ID in `lo..=hi`
+    /// when bigger blocks always expand into bigger IDs
+    fn blocks(
+        &self,
+        lo: &[u32],
+        hi: &[u32],
+        size: usize,
+        expand: impl Fn(&[u32]) -> Vec<u32>,
+    ) -> Option<(Id, Id)> {
+        let mut first = Id::from_digits(&lo[..size], self.base);
+        if expand(&lo[..size])[..] < *lo {
+            first = first.add_small(1);
+        }
+        let mut last = Id::from_digits(&hi[..size], self.base);
+        if expand(&hi[..size])[..] > *hi {
+            last = last.checked_sub(&Id::one())?;
+        }
+
+        // blocks with a digit too many never fit, and they're all after `last`
+        (first <= last).then_some((first, last))
+    }
+
+    /// what a `period` digit block is multiplied by to repeat it out to `len` digits, eg. 1001
+    fn multiplier(&self, len: u32, period: u32) -> Id {
+        (0..len / period)
+            .map(|i| Id::pow(self.base, i * period))
+            .sum()
+    }
+
+    /// the `len` digit part of `range`, as digits
+    fn clamp(&self, range: &IdRange, len: u32) -> Option<(Vec<u32>, Vec<u32>)> {
+        let smallest = Id::pow(self.base, len - 1);
+        let largest = Id::pow(self.base, len).checked_sub(&Id::one())?;
+        let lo = (&range.start).max(&smallest);
+        let hi = (&range.end).min(&largest);
+        (lo <= hi).then(|| (lo.digits(self.base), hi.digits(self.base)))
+    }
+}
+
+/// `half` mirrored out to a `len` digit palindrome
+fn mirror(half: &[u32], len: u32) -> Vec<u32> {
+    let mut digits = half.to_vec();
+    mirror_into(&mut digits, len);
+    digits
+}
+
+/// mirror the first half of a palindrome already in `digits` out to `len` digits
+fn mirror_into(digits: &mut Vec<u32>, len: u32) {
+    let half = digits.len();
+    for i in (0..len as usize - half).rev() {
+        digits.push(digits[i]);
+    }
+}
+
+fn mobius(mut n: u32) -> i32 {
+    let mut result = 1;
+    let mut p = 2;
+    while p * p <= n {
+        if n.is_multiple_of(p) {
+            n /= p;
+            if n.is_multiple_of(p) {
+                return 0;
+            }
+            result = -result;
+        }
+        p += 1;
+    }
+    if n > 1 { -result } else { result }
+}
+
+#[cfg(test)]
+mod test {
+    use proptest::prelude::*;
+
+    use crate::{
+        Id, IdRange, RepetitionRule, Scratch,
+        repeats::{Repetition, mobius},
+    };
+
+    /// the original check, one ID at a time
+    fn check_num(id: &str) -> bool {
+        let len = id.chars().count();
+        if !len.is_multiple_of(2) {
+            return false;
+        }
+
+        let (first, last) = id.split_at(len / 2);
+        first == last
+    }
+
+    fn check_repeated(id: &str) -> bool {
+        // if the next instance of id substr is less than halfway, then there's a pattern
+        let doubled = format!("{id}{id}");
+
+        // start from index 1 using slice, then add 1 back to get correct index
+        (doubled[1..].find(id).expect("No match in doubled") + 1) != id.len()
+    }
+
+    fn sum_matching(rule: &RepetitionRule, start: u64, end: u64) -> Id {
+        (start..=end)
+            .map(Id::from)
+            .filter(|id| rule.matches(id))
+            .sum()
+    }
+
+    fn rule() -> impl Strategy<Value = RepetitionRule> {
+        let repetition = prop_oneof![
+            (1..5u32).prop_map(Repetition::Exactly),
+            (1..5u32).prop_map(Repetition::AtLeast),
+            prop::collection::btree_set(1..5u32, 0..3).prop_map(Repetition::Periods),
+            Just(Repetition::Palindrome),
+        ];
+        (repetition, 2..17u32)
+            .prop_map(|(repetition, base)| RepetitionRule::new(repetition).in_base(base))
+    }
+
+    #[test]
+    fn it_should_match_longest_substr() {
+        let check_num = |id: &str| RepetitionRule::exactly(2).matches(&id.parse().unwrap());
+
+        let result = check_num("1010");
+        assert!(result);
+        assert!(!check_num("1"));
+        assert!(check_num("11"));
+        assert!(!check_num("101"));
+        assert!(check_num("38593859"));
+        assert!(!check_num("12"));
+        assert!(check_num("222222"));
+    }
+
+    #[test]
+    fn it_should_match_repeated() {
+        let check_repeated = |id: &str| RepetitionRule::at_least(2).matches(&id.parse().unwrap());
+
+        assert!(check_repeated("11"));
+        assert!(check_repeated("22"));
+        assert!(check_repeated("999"));
+        assert!(check_repeated("1188511885"));
+        assert!(check_repeated("2121212121"));
+
+        // invalid
+        assert!(!check_repeated("12"));
+        assert!(!check_repeated("121"));
+        assert!(!check_repeated("1001"));
+        assert!(!check_repeated("1021"));
+    }
+
+    #[test]
+    fn it_should_compute_mobius() {
+        let expected = [1, -1, -1, 0, -1, 1, -1, 0, 0, 1, -1, 0];
+        assert_eq!((1..=12).map(mobius).collect::<Vec<_>>(), expected);
+    }
+
+    #[test]
+    fn it_should_match_other_rules() {
+        let matches = |rule: &RepetitionRule, id: u64| rule.matches(&Id::from(id));
+
+        let thrice = RepetitionRule::exactly(3);
+        assert!(matches(&thrice, 121212));
+        assert!(matches(&thrice, 111));
+        assert!(!matches(&thrice, 1212));
+
+        let repdigits = RepetitionRule::periods([1]);
+        assert!(matches(&repdigits, 2222));
+        assert!(!matches(&repdigits, 2));
+        assert!(!matches(&repdigits, 1212));
+
+        assert!(matches(&RepetitionRule::palindromes(), 12321));
+        assert!(!matches(&RepetitionRule::palindromes(), 12331));
+
+        // 10 is 1010 in binary
+        assert!(matches(&RepetitionRule::exactly(2).in_base(2), 10));
+        assert!(!matches(&RepetitionRule::exactly(2), 10));
+    }
+
+    #[test]
+    fn it_should_share_scratch_between_ids() {
+        let mut scratch = Scratch::default();
+        for rule in [
+            RepetitionRule::at_least(2),
+            RepetitionRule::periods([2]).in_base(3),
+            RepetitionRule::palindromes(),
+        ] {
+            for id in (0..5_000).map(Id::from) {
+                assert_eq!(
+                    rule.period_with(&id, &mut scratch),
+                    rule.period(&id),
+                    "{id}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn it_should_sum_huge_ranges() {
+        // 11 + 22 + ... + 99 is the only two digit part
+        let small = IdRange::new(0, 99);
+        assert_eq!(RepetitionRule::exactly(2).sum(&small), Id::from(495));
+        assert_eq!(RepetitionRule::at_least(2).sum(&small), Id::from(495));
+
+        let everything = IdRange::new(0, u64::MAX);
+        let doubled = RepetitionRule::exactly(2).sum(&everything);
+        assert!(u64::try_from(&doubled).is_err());
+        assert!(RepetitionRule::at_least(2).sum(&everything) > doubled);
+        assert!(
+            !RepetitionRule::at_least(2)
+                .in_base(2)
+                .sum(&everything)
+                .is_zero()
+        );
+    }
+
+    #[test]
+    fn it_should_sum_palindromes_without_listing_them() {
+        // 1 to 9, 11 to 99, then 101a + 10b for a in 1..=9 and b in 0..=9
+        let three_digits = IdRange::new(0, 999);
+        assert_eq!(
+            RepetitionRule::palindromes().sum(&three_digits),
+            Id::from(45 + 495 + 49500)
+        );
+
+        // 10^8 or so palindromes, far too many to go through one at a time
+        let sixteen_digits = IdRange::new(0, 10u64.pow(16));
+        let fifteen_digits = IdRange::new(0, 10u64.pow(15));
+        assert!(
+            RepetitionRule::palindromes().sum(&sixteen_digits)
+                > RepetitionRule::palindromes().sum(&fifteen_digits)
+        );
+    }
+
+    #[test]
+    fn it_should_find_ids_longer_than_a_u64() {
+        let block: Id = "1234567890123456789012345".parse().unwrap();
+        let id: Id = format!("{block}{block}").parse().unwrap();
+        let range = IdRange {
+            start: id.checked_sub(&Id::from(1)).unwrap(),
+            end: id.add_small(1),
+        };
+
+        assert_eq!(RepetitionRule::exactly(2).period(&id), Some(25));
+        assert_eq!(RepetitionRule::exactly(2).sum(&range), id);
+        assert_eq!(RepetitionRule::at_least(2).sum(&range), id);
+        assert!(RepetitionRule::palindromes().sum(&range).is_zero());
+
+        // every 40 digit ID that's 20 digits twice: 10..0 to 99..9 times 10..01
+        let start = Id::pow(10, 39);
+        let range = IdRange {
+            end: Id::pow(10, 40).checked_sub(&Id::one()).unwrap(),
+            start,
+        };
+        let (first, last) = (
+            Id::pow(10, 19),
+            Id::pow(10, 20).checked_sub(&Id::one()).unwrap(),
+        );
+        let count = last.checked_sub(&first).unwrap().add_small(1);
+        let blocks = (&(&first + &last) * &count).div_small(2).0;
+        let multiplier = Id::pow(10, 20).add_small(1);
+        assert_eq!(
+            RepetitionRule::exactly(2).sum(&range),
+            &blocks * &multiplier
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn it_should_match_like_the_original_checks(id in 1..u64::MAX) {
+            let id_str = id.to_string();
+            let id = Id::from(id);
+            prop_assert_eq!(RepetitionRule::exactly(2).matches(&id), check_num(&id_str));
+            prop_assert_eq!(RepetitionRule::at_least(2).matches(&id), check_repeated(&id_str));
+        }
+
+        #[test]
+        fn it_should_sum_like_checking_every_id(
+            rule in rule(),
+            start in prop_oneof![0..2_000_000u64, 999_900_000u64..1_000_100_000],
+            len in 0..2_000u64,
+        ) {
+            let range = IdRange::new(start, start + len);
+            prop_assert_eq!(rule.sum(&range), sum_matching(&rule, start, start + len));
+        }
+    }
+}
@@ -0,0 +1,286 @@
+use aoc_core::{
+    ParseError, Solution, Streamer, Streaming,
+    monotonic::{MonotonicStack, Order},
+    stream,
+};
+
+mod selection;
+
+pub use selection::{Selection, write_selections};
+
+pub struct Day03;
+
+/// how many batteries to turn on in each bank for part 2
+const PART_2_BATTERIES: usize = 12;
+
+impl Solution for Day03 {
+    const DAY: u8 = 3;
+
+    type Input<'a> = Vec<Bank<'a>>;
+    type Part1 = u32;
+    type Part2 = u64;
+
+    const STREAM: Option<Streamer> = Some(stream::<Self>);
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        input
+            .lines()
+            .map(|line| Bank::try_from(line).map_err(|e| e.within(input, line)))
+            .collect()
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Self::Part1 {
+        calc_joltage(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Self::Part2 {
+        calc_joltage_part_2(input)
+    }
+}
+
+impl Streaming for Day03 {
+    /// joltage so far for each part
+    type State = (u32, u64);
+
+    fn feed((part1, part2): &mut Self::State, line: &str) -> Result<(), ParseError> {
+        let bank = Bank::try_from(line)?;
+        *part1 += find_largest(bank.as_str()).joltage as u32;
+        *part2 += find_joltage(bank.as_str(), PART_2_BATTERIES).joltage;
+        Ok(())
+    }
+
+    fn finish(state: Self::State) -> Result<(Self::Part1, Self::Part2), ParseError> {
+        Ok(state)
+    }
+}
+
+/// A line of battery joltages, checked to be all digits and long enough for part 2
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Bank<'a>(&'a str);
+
+impl<'a> Bank<'a> {
+    pub fn as_str(&self) -> &'a str {
+        self.0
+    }
+}
+
+impl<'a> TryFrom<&'a str> for Bank<'a> {
+    type Error = ParseError;
+
+    fn try_from(line: &'a str) -> Result<Self, Self::Error> {
+        if let Some((i, c)) = line.char_indices().find(|(_, c)| !c.is_ascii_digit()) {
+            return Err(ParseError::at(
+                line,
+                &line[i..i + c.len_utf8()],
+                format!("'{c}' isn't a joltage"),
+            ));
+        }
+        if line.len() < PART_2_BATTERIES {
+            return Err(ParseError::at(
+                line,
+                line,
+                format!("banks need at least {PART_2_BATTERIES} batteries"),
+            ));
+        }
+
+        Ok(Bank(line))
+    }
+}
+
+pub fn calc_joltage(banks: &[Bank]) -> u32 {
+    let mut total = 0;
+    for bank in banks {
+        // two digits always fit
+        total += find_largest(bank.as_str()).joltage as u32;
+    }
+
+    total
+}
+pub fn calc_joltage_part_2(banks: &[Bank]) -> u64 {
+    let mut total = 0;
+    for bank in banks {
+        total += find_joltage(bank.as_str(), PART_2_BATTERIES).joltage;
+    }
+
+    total
+}
+
+pub fn stack_joltage(banks: &[Bank]) -> u64 {
+    let mut total = 0;
+    for bank in banks {
+        total += do_a_stack(bank.as_str(), PART_2_BATTERIES).joltage;
+    }
+
+    total
+}
+
+#[derive(Clone, Copy)]
+struct Record {
+    num: u32,
+    index: usize,
+}
+
+pub fn find_largest(bank: &str) -> Selection {
+    let mut first = Record { num: 0, index: 0 };
+
+    // first
+    let chars: Vec<u32> = bank
+        .chars()
+        .map(|n| n.to_digit(10).expect("Not a number"))
+        .collect();
+
+    // don't check last - need to leave a space for second
+    chars
+        .iter()
+        .enumerate()
+        .take(chars.len() - 1)
+        .for_each(|(i, num)| {
+            if *num > first.num {
+                first = Record {
+                    num: *num,
+                    index: i,
+                };
+            }
+        });
+
+    // second
+    let mut second = Record {
+        num: 0,
+        index: first.index + 1,
+    };
+    chars
+        .iter()
+        .enumerate()
+        // results have to be in order
+        .skip(first.index + 1)
+        .for_each(|(i, num)| {
+            if *num > second.num {
+                second = Record {
+                    num: *num,
+                    index: i,
+                };
+            }
+        });
+
+    Selection::new(bank, vec![first.index, second.index])
+}
+
+pub fn find_joltage(bank: &str, max_len: usize) -> Selection {
+    let bytes = bank.as_bytes();
+
+    let collection = vec![];
+    let positions = find_x(bytes, collection, -1, max_len);
+
+    Selection::new(bank, positions)
+}
+
+/// keep the biggest digits in order with a decreasing stack, which can only drop as many
+/// digits as there are to spare
+pub fn do_a_stack(input: &str, max_len: usize) -> Selection {
+    let bytes = input.as_bytes();
+
+    // ordered by digit, keeping where each one came from
+    let mut stack = MonotonicStack::by(Order::Decreasing, |a: &(u8, usize), b| a.0.cmp(&b.0))
+        .with_budget(bytes.len() - max_len);
+    stack.extend(bytes.iter().copied().zip(0..));
+
+    let positions = stack
+        .as_slice()
+        .iter()
+        // if digits already in order then stack won't pop
+        // so limit to max_len
+        .take(max_len)
+        .map(|(_, i)| *i)
+        .collect();
+
+    Selection::new(input, positions)
+}
+
+// recurse, collecting the positions picked
+fn find_x(chars: &[u8], mut collection: Vec<usize>, start: i32, to_find: usize) -> Vec<usize> {
+    if to_find == 0 {
+        return collection;
+    }
+
+    // create a window, to exclude the last found index and making sure there are
+    // enough digits left over for the remainder (since digits have to stay in order)
+    // we're finding 12 so make sure there are enough digits left over
+    let end = chars.len() + 1 - to_find;
+
+    let mut found = 0;
+    let mut index = 0;
+    let slice = &chars[(start + 1) as usize..end];
+
+    slice.iter().enumerate().for_each(|(i, num)| {
+        if *num > found {
+            found = *num;
+            index = i;
+        }
+    });
+
+    // index is for the slice, so add back chars index
+    let picked = index as i32 + start + 1;
+    collection.push(picked as usize);
+
+    find_x(chars, collection, picked, to_find - 1)
+}
+
+#[cfg(test)]
+mod test {
+    use aoc_core::Solution;
+
+    use crate::{Day03, do_a_stack, find_joltage, find_largest};
+
+    aoc_core::example_files!(Day03);
+
+    #[test]
+    fn it_should_find_largest_2_digits() {
+        assert_eq!(find_largest("987654321111111").joltage, 98);
+        assert_eq!(find_largest("811111111111119").joltage, 89);
+        assert_eq!(find_largest("234234234234278").joltage, 78);
+        assert_eq!(find_largest("818181911112111").joltage, 92);
+    }
+
+    #[test]
+    fn it_should_find_twelve_largest() {
+        assert_eq!(find_joltage("987654321111111", 12).joltage, 987654321111);
+        assert_eq!(find_joltage("811111111111119", 12).joltage, 811111111119);
+        assert_eq!(find_joltage("234234234234278", 12).joltage, 434234234278);
+        assert_eq!(find_joltage("818181911112111", 12).joltage, 888911112111);
+    }
+
+    #[test]
+    fn stack_should_find_largest() {
+        assert_eq!(do_a_stack("987654321111111", 12).joltage, 987654321111);
+        assert_eq!(do_a_stack("234234234234278", 12).joltage, 434234234278);
+        assert_eq!(do_a_stack("811111111111119", 12).joltage, 811111111119);
+        assert_eq!(do_a_stack("818181911112111", 12).joltage, 888911112111);
+        assert_eq!(do_a_stack("999999999999", 12).joltage, 999999999999);
+    }
+
+    #[test]
+    fn it_should_pick_the_same_batteries_either_way() {
+        for bank in [
+            "987654321111111",
+            "818181911112111",
+            "3332221119993",
+            "000000000000",
+        ] {
+            let recursive = find_joltage(bank, 12);
+            assert_eq!(do_a_stack(bank, 12), recursive, "{bank}");
+            assert!(recursive.positions.is_sorted_by(|a, b| a < b), "{bank}");
+        }
+        assert_eq!(find_largest("0000").positions, [0, 1]);
+        assert_eq!(find_joltage("8190", 2).positions, [2, 3]);
+    }
+
+    #[test]
+    fn it_should_report_bad_banks() {
+        let err = Day03::parse("987654321111111\n81111x111111119").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 6));
+        assert_eq!(err.message, "'x' isn't a joltage");
+
+        let err = Day03::parse("9876").unwrap_err();
+        assert_eq!(err.message, "banks need at least 12 batteries");
+    }
+}
@@ -0,0 +1,125 @@
+use std::io::{self, Write};
+
+use aoc_core::Part;
+
+use crate::{Bank, PART_2_BATTERIES, do_a_stack, find_joltage, find_largest};
+
+/// The batteries picked from a bank, by position, and the joltage they make
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Selection {
+    /// indices into the bank, in order
+    pub positions: Vec<usize>,
+    pub joltage: u64,
+}
+
+impl Selection {
+    /// read the joltage off the digits at `positions`
+    pub fn new(bank: &str, positions: Vec<usize>) -> Self {
+        let bytes = bank.as_bytes();
+        let joltage = positions
+            .iter()
+            .fold(0, |joltage, i| joltage * 10 + u64::from(bytes[*i] - b'0'));
+        Self { positions, joltage }
+    }
+
+    /// `bank` with a `^` under every picked battery
+    ///
+    /// ```
+    /// use day03::find_largest;
+    ///
+    /// let bank = "818181911112111";
+    /// assert_eq!(find_largest(bank).highlight(bank), "818181911112111\n      ^    ^   ");
+    /// ```
+    pub fn highlight(&self, bank: &str) -> String {
+        format!("{bank}\n{}", self.marks(bank))
+    }
+
+    /// just the line of carets from [`Selection::highlight`]
+    pub fn marks(&self, bank: &str) -> String {
+        let mut marks = vec![b' '; bank.len()];
+        for i in &self.positions {
+            marks[*i] = b'^';
+        }
+        String::from_utf8(marks).expect("spaces and carets")
+    }
+}
+
+/// print what `part`'s solver and the stack pick out of each bank, one under the other, eg.
+///
+/// ```text
+///           818181911112111
+/// recursive ^ ^ ^ ^^^^^^^^^
+/// stack     ^ ^ ^ ^^^^^^^^^
+/// joltage=888911112111
+/// ```
+pub fn write_selections(
+    out: &mut (impl Write + ?Sized),
+    banks: &[Bank],
+    part: Part,
+) -> io::Result<()> {
+    let (name, batteries, solve): (&str, usize, fn(&str) -> Selection) = match part {
+        Part::One => ("largest", 2, find_largest),
+        Part::Two => ("recursive", PART_2_BATTERIES, |bank| {
+            find_joltage(bank, PART_2_BATTERIES)
+        }),
+    };
+    let width = name.len().max("stack".len());
+
+    for bank in banks {
+        let bank = bank.as_str();
+        let solved = solve(bank);
+        let stack = do_a_stack(bank, batteries);
+
+        writeln!(out, "{:width$} {bank}", "")?;
+        writeln!(out, "{name:width$} {}", solved.marks(bank))?;
+        writeln!(out, "{:width$} {}", "stack", stack.marks(bank))?;
+        if solved.joltage == stack.joltage {
+            writeln!(out, "joltage={}", solved.joltage)?;
+        } else {
+            writeln!(
+                out,
+                "joltage={} ({name}) {} (stack)",
+                solved.joltage, stack.joltage
+            )?;
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use aoc_core::{Part, Solution};
+
+    use crate::{Day03, Selection, write_selections};
+
+    #[test]
+    fn it_should_read_the_joltage_off_the_positions() {
+        let selection = Selection::new("8190", vec![0, 3]);
+        assert_eq!(selection.joltage, 80);
+        assert_eq!(selection.highlight("8190"), "8190\n^  ^");
+    }
+
+    #[test]
+    fn it_should_write_both_strategies() {
+        let banks = Day03::parse("818181911112111").unwrap();
+        let mut out = vec![];
+        write_selections(&mut out, &banks, Part::Two).unwrap();
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "          818181911112111\n\
+             recursive ^ ^ ^ ^^^^^^^^^\n\
+             stack     ^ ^ ^ ^^^^^^^^^\n\
+             joltage=888911112111\n"
+        );
+
+        let mut out = vec![];
+        write_selections(&mut out, &banks, Part::One).unwrap();
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "        818181911112111\n\
+             largest       ^    ^   \n\
+             stack         ^    ^   \n\
+             joltage=92\n"
+        );
+    }
+}
@@ -0,0 +1,205 @@
+use aoc_core::{ParseError, Solution};
+use std::{collections::HashSet, str::FromStr};
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    const DAY: u8 = 4;
+
+    type Input<'a> = Map;
+    type Part1 = u16;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        input.parse()
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Self::Part1 {
+        calc_reachable(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Self::Part2 {
+        remove(input)
+    }
+}
+
+pub fn calc_reachable(map: &Map) -> u16 {
+    // must be fewer than 4 rolls of paper nearby
+    let mut total = 0;
+
+    for roll in &map.rolls {
+        if map.count_neighbours(roll) < 4 {
+            total += 1;
+        }
+    }
+    total
+}
+
+pub fn remove(map: &Map) -> usize {
+    let mut map = map.clone();
+    let mut to_remove = can_remove(&map);
+    let mut count = 0;
+
+    while !to_remove.is_empty() {
+        count += to_remove.len();
+        for roll in to_remove {
+            map.rolls.remove(&roll);
+        }
+        // recurse
+        // to_remove = 0;
+        to_remove = can_remove(&map);
+    }
+
+    count
+}
+
+fn can_remove(map: &Map) -> HashSet<Position> {
+    let mut to_remove = HashSet::new();
+
+    for roll in &map.rolls {
+        if map.count_neighbours(roll) < 4 {
+            to_remove.insert(roll.clone());
+        }
+    }
+
+    to_remove
+}
+
+#[derive(PartialEq, Debug, Clone)]
+pub struct Map {
+    map: Vec<char>,
+    width: usize,
+    height: usize,
+    rolls: HashSet<Position>,
+}
+
+#[derive(Hash, PartialEq, Eq, Debug, Clone)]
+struct Position(usize, usize);
+
+impl FromStr for Map {
+    type Err = ParseError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let mut rolls = HashSet::new();
+        let mut map = vec![];
+        let mut width = None;
+        let mut height = 0;
+
+        for line in input.lines() {
+            let row = line.trim();
+            if let Some((i, c)) = row.char_indices().find(|(_, c)| *c != '@' && *c != '.') {
+                return Err(ParseError::at(
+                    input,
+                    &row[i..i + c.len_utf8()],
+                    format!("expected '@' or '.', got '{c}'"),
+                ));
+            }
+
+            // rows all have to be the same length to find neighbours
+            match width {
+                None => width = Some(row.len()),
+                Some(width) if width != row.len() => {
+                    return Err(ParseError::at(
+                        input,
+                        row,
+                        format!("row is {} wide, expected {width}", row.len()),
+                    ));
+                }
+                _ => {}
+            }
+
+            row.chars().enumerate().for_each(|(index, item)| {
+                if item == '@' {
+                    rolls.insert(Position(index, height));
+                }
+            });
+            map.extend(row.chars());
+            height += 1;
+        }
+
+        let width = width.ok_or_else(|| ParseError::at(input, input, "empty map"))?;
+
+        Ok(Map {
+            width,
+            height,
+            map,
+            rolls,
+        })
+    }
+}
+
+impl Map {
+    fn count_neighbours(&self, pos: &Position) -> u8 {
+        let mut count = 0;
+
+        if pos.0 > 0 {
+            // east
+            if self.rolls.contains(&Position(pos.0 - 1, pos.1)) {
+                count += 1;
+            }
+
+            if pos.1 > 0 {
+                // northeast
+                if self.rolls.contains(&Position(pos.0 - 1, pos.1 - 1)) {
+                    count += 1;
+                }
+            }
+            if pos.1 < self.height {
+                // southeast
+                if self.rolls.contains(&Position(pos.0 - 1, pos.1 + 1)) {
+                    count += 1;
+                }
+            }
+        }
+        if pos.0 < self.width {
+            // west
+            if self.rolls.contains(&Position(pos.0 + 1, pos.1)) {
+                count += 1;
+            }
+            if pos.1 > 0 {
+                // northwest
+                if self.rolls.contains(&Position(pos.0 + 1, pos.1 - 1)) {
+                    count += 1;
+                }
+            }
+            if pos.1 < self.height {
+                // southwest
+                if self.rolls.contains(&Position(pos.0 + 1, pos.1 + 1)) {
+                    count += 1;
+                }
+            }
+        }
+        if pos.1 > 0 {
+            // north
+            if self.rolls.contains(&Position(pos.0, pos.1 - 1)) {
+                count += 1;
+            }
+        }
+        if pos.1 < self.height {
+            // south
+            if self.rolls.contains(&Position(pos.0, pos.1 + 1)) {
+                count += 1;
+            }
+        }
+
+        count
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{Day04, Map};
+
+    aoc_core::example_files!(Day04);
+
+    #[test]
+    fn it_should_report_bad_maps() {
+        let err = "..@@.\n@@x..".parse::<Map>().unwrap_err();
+        assert_eq!((err.line, err.column), (2, 3));
+
+        let err = "..@@.\n@@.".parse::<Map>().unwrap_err();
+        assert_eq!(err.message, "row is 3 wide, expected 5");
+
+        assert!("".parse::<Map>().is_err());
+    }
+}
@@ -0,0 +1,393 @@
+use aoc_core::{ParseError, Solution, Streamer, Streaming, stream};
+use std::ops::RangeInclusive;
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    const DAY: u8 = 5;
+
+    type Input<'a> = (Ranges, Vec<u64>);
+    type Part1 = u64;
+    type Part2 = u64;
+
+    const STREAM: Option<Streamer> = Some(stream::<Self>);
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        process_input(input)
+    }
+
+    fn part1((ranges, ids): &Self::Input<'_>) -> Self::Part1 {
+        count_fresh(ids, ranges)
+    }
+
+    fn part2((ranges, _ids): &Self::Input<'_>) -> Self::Part2 {
+        total_valid(ranges)
+    }
+}
+
+pub type Ranges = Vec<RangeInclusive<u64>>;
+
+/// Ranges are kept until the blank line, after that each ID is checked and forgotten
+#[derive(Debug, Default)]
+pub struct Inventory {
+    ranges: Ranges,
+    merged: Option<Ranges>,
+    fresh: u64,
+}
+
+impl Streaming for Day05 {
+    type State = Inventory;
+
+    fn feed(inventory: &mut Self::State, line: &str) -> Result<(), ParseError> {
+        match &inventory.merged {
+            None if line.is_empty() => {
+                if inventory.ranges.is_empty() {
+                    return Err(no_ranges(line));
+                }
+                inventory.merged = Some(merge_ranges(&mut inventory.ranges));
+            }
+            None => inventory.ranges.push(parse_range(line)?),
+            Some(merged) => {
+                if is_fresh(parse_id(line)?, merged) {
+                    inventory.fresh += 1;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn finish(inventory: Self::State) -> Result<(Self::Part1, Self::Part2), ParseError> {
+        // like `process_input`, the IDs can be missing but not the blank line
+        let merged = inventory.merged.ok_or_else(|| no_blank_line(""))?;
+        Ok((inventory.fresh, total_valid(&merged)))
+    }
+}
+
+fn sort_ranges(ranges: &mut Ranges) -> &mut Ranges {
+    ranges.sort_by(|a, b| {
+        if a.start() < b.start() {
+            std::cmp::Ordering::Less
+        } else {
+            std::cmp::Ordering::Greater
+        }
+    });
+
+    ranges
+}
+
+pub fn merge_ranges(ranges: &mut Ranges) -> Ranges {
+    // 3-5
+    // 10-14
+    // 16-20
+    // 12-18
+    // -> 3-5,10-20
+    sort_ranges(ranges);
+
+    let mut merged: Ranges = vec![];
+    // if no overlap, add to merged
+    // if overlap, extend then check against more
+    ranges.iter().enumerate().for_each(|(index, current)| {
+        // if merged prev
+        let mut current = current.clone();
+        let mut should_check = true;
+
+        for prev in &merged {
+            // current range completely in prev
+            if current.start() >= prev.start() && current.end() <= prev.end() {
+                should_check = false;
+            }
+        }
+
+        if should_check {
+            ranges.iter().skip(index + 1).for_each(|next| {
+                // if a.end > b.start && a.end < b.end
+                if current.start() <= next.start()
+                    && (current.end() >= next.start()
+                    // eg 2-2 3-5 - should extend
+                        || current.end() + 1 >= *next.start())
+                {
+                    // a.end is in b range
+                    // b.start = lesser of a.start and b.start
+                    current = *current.start().min(next.start())..=*next.end().max(current.end());
+                }
+            });
+
+            merged.push(current);
+        }
+    });
+
+    merged
+}
+
+pub fn count_fresh(ids: &[u64], ranges: &Ranges) -> u64 {
+    let mut count = 0;
+    for id in ids {
+        if is_fresh(*id, ranges) {
+            count += 1;
+        }
+    }
+
+    count
+}
+
+pub fn process_input(input: &str) -> Result<(Ranges, Vec<u64>), ParseError> {
+    // split on lines rather than "\n\n" so CRLF input is read the same as when streaming
+    let blank = input
+        .lines()
+        .position(str::is_empty)
+        .ok_or_else(|| no_blank_line(input))?;
+    if blank == 0 {
+        return Err(no_ranges(input));
+    }
+
+    let mut lines = input.lines();
+    let mut ranges = vec![];
+
+    for range in lines.by_ref().take(blank) {
+        ranges.push(parse_range(range).map_err(|e| e.within(input, range))?);
+    }
+
+    let ids = lines
+        .skip(1)
+        .map(|id| parse_id(id).map_err(|e| e.within(input, id)))
+        .collect::<Result<_, _>>()?;
+
+    Ok((merge_ranges(&mut ranges), ids))
+}
+
+fn parse_range(range: &str) -> Result<RangeInclusive<u64>, ParseError> {
+    let (start, end) = range
+        .split_once("-")
+        .ok_or_else(|| ParseError::at(range, range, "expected a range like 3-5"))?;
+
+    let start = parse_id(start).map_err(|e| e.within(range, start))?;
+    let end = parse_id(end).map_err(|e| e.within(range, end))?;
+    if end < start {
+        return Err(ParseError::at(
+            range,
+            range,
+            format!("range {range} ends before it starts"),
+        ));
+    }
+
+    Ok(start..=end)
+}
+
+/// for input that ends without one, pointing at the end of `text`
+fn no_blank_line(text: &str) -> ParseError {
+    ParseError::at(
+        text,
+        &text[text.len()..],
+        "expected a blank line between the ranges and the IDs",
+    )
+}
+
+/// for a blank line before any ranges
+fn no_ranges(text: &str) -> ParseError {
+    ParseError::at(
+        text,
+        &text[..0],
+        "expected at least one range before the blank line",
+    )
+}
+
+fn parse_id(id: &str) -> Result<u64, ParseError> {
+    id.parse::<u64>()
+        .map_err(|_| ParseError::at(id, id, format!("'{id}' isn't an ID")))
+}
+
+pub fn is_fresh(id: u64, ranges: &Ranges) -> bool {
+    for range in ranges {
+        if range.contains(&id) {
+            return true;
+        }
+    }
+    false
+}
+
+/// how many IDs the ranges cover, which have to be merged already
+pub fn total_valid(ranges: &[RangeInclusive<u64>]) -> u64 {
+    // 3..5
+    // 10..20
+    // = 3 + 11
+    ranges
+        .iter()
+        .map(|range| range.end() - range.start() + 1)
+        .sum()
+}
+
+#[cfg(test)]
+mod test {
+    use aoc_core::{Error, Part, Report, solve, stream};
+
+    use crate::{Day05, count_fresh, is_fresh, merge_ranges, process_input, total_valid};
+
+    aoc_core::example_files!(Day05);
+
+    #[test]
+    fn it_should_process_input() {
+        let example_input = "3-5
+10-14
+16-20
+12-18
+
+1
+5
+8
+11
+17
+32";
+        assert_eq!(
+            process_input(example_input).unwrap(),
+            (vec![3..=5, 10..=20], vec![1, 5, 8, 11, 17, 32])
+        );
+    }
+
+    #[test]
+    fn it_should_check_freshness() {
+        let ranges = vec![3..=5, 10..=14, 16..=20, 12..=18];
+
+        assert!(!is_fresh(1, &ranges));
+        assert!(!is_fresh(8, &ranges));
+        assert!(!is_fresh(32, &ranges));
+        assert!(is_fresh(5, &ranges));
+        assert!(is_fresh(11, &ranges));
+        assert!(is_fresh(17, &ranges));
+    }
+
+    #[test]
+    fn it_should_count_fresh_ingredients() {
+        let ranges = vec![3..=5, 10..=14, 16..=20, 12..=18];
+        let ids = vec![1, 5, 8, 11, 17, 32];
+
+        assert_eq!(count_fresh(&ids, &ranges), 3);
+    }
+
+    #[test]
+    fn should_merge_ranges() {
+        assert_eq!(merge_ranges(&mut vec![1..=10, 2..=9]), vec![1..=10]);
+        assert_eq!(
+            merge_ranges(&mut vec![3..=5, 10..=14, 16..=20, 12..=18]),
+            vec![3..=5, 10..=20]
+        );
+        assert_eq!(
+            merge_ranges(&mut vec![
+                3..=5,
+                10..=10,
+                10..=14,
+                14..=14,
+                14..=20,
+                12..=18
+            ]),
+            vec![3..=5, 10..=20]
+        );
+        assert_eq!(
+            merge_ranges(&mut vec![3..=5, 10..=14, 11..=12, 14..=20, 12..=18]),
+            vec![3..=5, 10..=20]
+        );
+        assert_eq!(
+            merge_ranges(&mut vec![
+                35269914317143..=39695782939342,
+                41365168848672..=41365168848672,
+                41365168848672..=49031435034747,
+                555240684135725..=555240684135725,
+                555240684135726..=560251920336867
+            ]),
+            vec![
+                35269914317143..=39695782939342,
+                41365168848672..=49031435034747,
+                555240684135725..=560251920336867
+            ]
+        );
+    }
+
+    #[test]
+    fn it_should_count_in_ranges() {
+        assert_eq!(total_valid(&[3..=5, 10..=20]), 14);
+        assert_eq!(total_valid(&[3..=5, 10..=20, 22..=23]), 16);
+        assert_eq!(total_valid(&[0..=5]), 6);
+        assert_eq!(total_valid(&[]), 0);
+    }
+
+    #[test]
+    fn it_should_report_bad_input() {
+        let err = process_input("3-5\n10-1x\n\n1").unwrap_err();
+        assert_eq!((err.line, err.column, err.len), (2, 4, 2));
+
+        let err = process_input("3-5\n\n1\nx").unwrap_err();
+        assert_eq!((err.line, err.column), (4, 1));
+        assert_eq!(err.message, "'x' isn't an ID");
+
+        let err = process_input("3-5\n1").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 2));
+
+        let err = process_input("\n\n1\n").unwrap_err();
+        assert_eq!((err.line, err.column), (1, 1));
+        assert_eq!(
+            err.message,
+            "expected at least one range before the blank line"
+        );
+
+        let err = process_input("3-5\n5-3\n\n1").unwrap_err();
+        assert_eq!((err.line, err.column, err.len), (2, 1, 3));
+        assert_eq!(err.message, "range 5-3 ends before it starts");
+    }
+
+    #[test]
+    fn it_should_count_ranges_starting_at_zero() {
+        let (ranges, ids) = process_input("0-5\n\n1").unwrap();
+        assert_eq!((count_fresh(&ids, &ranges), total_valid(&ranges)), (1, 6));
+    }
+
+    #[test]
+    fn it_should_stream_the_same_as_parsing() {
+        for input in [
+            "3-5\n10-14\n\n1\n12\n",
+            "3-5\n",
+            "",
+            "\n\n1",
+            "5-3\n\n1",
+            "3-5\n\n",
+            "3-5\r\n10-14\r\n\r\n1\r\n12\r\n",
+        ] {
+            let solved = solve::<Day05>(input, &Part::ALL).map_err(Error::from);
+            let streamed = stream::<Day05>(&mut input.as_bytes(), &Part::ALL);
+            match (solved, streamed) {
+                (Ok(solved), Ok(streamed)) => {
+                    let values = |report: Report| -> Vec<String> {
+                        report.answers.into_iter().map(|a| a.value).collect()
+                    };
+                    assert_eq!(values(solved), values(streamed), "{input:?}");
+                }
+                (Err(Error::Parse(solved)), Err(Error::Parse(streamed))) => {
+                    assert_eq!(
+                        (solved.line, solved.column, solved.message),
+                        (streamed.line, streamed.column, streamed.message),
+                        "{input:?}"
+                    );
+                }
+                (solved, streamed) => panic!("{input:?}: {solved:?} but {streamed:?} streaming"),
+            }
+        }
+    }
+
+    #[test]
+    fn it_should_report_bad_lines_while_streaming() {
+        let err = stream::<Day05>(&mut "3-5\n10-14\n\n1\nx".as_bytes(), &Part::ALL).unwrap_err();
+        let Error::Parse(err) = err else {
+            panic!("expected a parse error, got {err}");
+        };
+
+        assert_eq!((err.day, err.line, err.column), (5, 5, 1));
+        assert_eq!(err.message, "'x' isn't an ID");
+
+        let err = stream::<Day05>(&mut "\n1\n".as_bytes(), &Part::ALL).unwrap_err();
+        let Error::Parse(err) = err else {
+            panic!("expected a parse error, got {err}");
+        };
+        assert_eq!(
+            err.message,
+            "expected at least one range before the blank line"
+        );
+    }
+}
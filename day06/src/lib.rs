@@ -0,0 +1,319 @@
+use aoc_core::{ParseError, Solution};
+use itertools::Itertools;
+use std::str::FromStr;
+
+pub struct Day06;
+
+/// part 2 reads the columns differently, so keep both layouts
+pub struct Homework {
+    rows: (Vec<Operation>, Vec<Vec<u64>>),
+    columns: (Vec<Operation>, Vec<Vec<u64>>),
+}
+
+impl Solution for Day06 {
+    const DAY: u8 = 6;
+
+    type Input<'a> = Homework;
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        Ok(Homework {
+            rows: parse_input(input)?,
+            columns: parse_input_part_2(input)?,
+        })
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Self::Part1 {
+        let (ops, nums) = &input.rows;
+        do_homework(ops, nums)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Self::Part2 {
+        let (ops, nums) = &input.columns;
+        do_homework_part_2(ops, nums)
+    }
+}
+
+#[derive(PartialEq, Eq, Debug)]
+pub enum Operation {
+    Multiply,
+    Add,
+}
+
+impl FromStr for Operation {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "*" => Ok(Self::Multiply),
+            "+" => Ok(Self::Add),
+            _ => Err(ParseError::at(s, s, format!("expected * or +, got '{s}'"))),
+        }
+    }
+}
+
+pub fn parse_input(input: &str) -> Result<(Vec<Operation>, Vec<Vec<u64>>), ParseError> {
+    let mut lines = input.lines().rev();
+    // operations are on the last line, numbers above
+    let ops = parse_ops(input, lines.next())?;
+
+    let container: Vec<Vec<u64>> = lines
+        .map(|line| {
+            let row = line
+                .split_whitespace()
+                .map(|el| {
+                    el.parse::<u64>()
+                        .map_err(|_| ParseError::at(input, el, format!("'{el}' isn't a number")))
+                })
+                .collect::<Result<Vec<u64>, _>>()?;
+
+            if row.len() != ops.len() {
+                return Err(ParseError::at(
+                    input,
+                    line,
+                    format!("found {} numbers for {} operations", row.len(), ops.len()),
+                ));
+            }
+
+            Ok(row)
+        })
+        .collect::<Result<_, _>>()?;
+
+    if container.is_empty() {
+        return Err(ParseError::at(input, input, "no numbers"));
+    }
+
+    Ok((ops, container))
+}
+
+fn parse_ops(input: &str, line: Option<&str>) -> Result<Vec<Operation>, ParseError> {
+    let line = line.ok_or_else(|| ParseError::at(input, input, "no operations"))?;
+
+    line.split_whitespace()
+        .map(|el| el.parse::<Operation>().map_err(|e| e.within(input, el)))
+        .collect()
+}
+
+pub fn parse_input_part_2(input: &str) -> Result<(Vec<Operation>, Vec<Vec<u64>>), ParseError> {
+    let ops = parse_ops(input, input.lines().last())?;
+
+    let lines: Vec<&str> = input.lines().collect();
+    let rows = &lines[..lines.len() - 1];
+    let width = rows
+        .first()
+        .ok_or_else(|| ParseError::at(input, input, "no numbers"))?
+        .chars()
+        .count();
+
+    // reading down the columns needs every row to be at least as wide as the first
+    if let Some(row) = rows.iter().find(|row| row.chars().count() < width) {
+        return Err(ParseError::at(
+            input,
+            row,
+            format!("row is {} wide, expected {width}", row.chars().count()),
+        ));
+    }
+
+    let container: Vec<Vec<char>> = rows.iter().map(|line| line.chars().collect()).collect();
+
+    let flipped: Vec<Vec<char>> = transpose(container);
+    let mapped: Vec<String> = flipped
+        .iter()
+        .map(|arr| {
+            arr.iter().fold(String::new(), |mut acc, c| {
+                acc.push(*c);
+                acc
+            })
+        })
+        .collect();
+
+    let mut grouped = vec![];
+
+    // keep the column index around for errors
+    for (_key, chunk) in &mapped
+        .into_iter()
+        .enumerate()
+        .chunk_by(|(_, item)| item.trim() != "")
+    {
+        grouped.push(chunk.collect::<Vec<(usize, String)>>());
+    }
+
+    let bad_column = |col: usize| {
+        // point at whichever row has something other than a digit in this column
+        let row = rows
+            .iter()
+            .find(|row| {
+                row.chars()
+                    .nth(col)
+                    .is_some_and(|c| !c.is_ascii_digit() && c != ' ')
+            })
+            .unwrap_or(&rows[0]);
+        let (i, c) = row.char_indices().nth(col).expect("rows checked for width");
+
+        ParseError::at(input, &row[i..i + c.len_utf8()], "column isn't a number")
+    };
+
+    let parsed: Vec<Vec<u64>> = grouped
+        .iter()
+        .filter(|n| !(n.len() == 1 && n[0].1.trim() == ""))
+        .map(|arr| {
+            arr.iter()
+                .map(|(col, n)| n.trim().parse::<u64>().map_err(|_| bad_column(*col)))
+                .collect::<Result<Vec<u64>, _>>()
+        })
+        .collect::<Result<_, _>>()?;
+
+    Ok((ops, parsed))
+}
+
+pub fn do_homework_part_2(ops: &[Operation], nums: &[Vec<u64>]) -> u64 {
+    nums.iter().enumerate().fold(0, |acc, (i, n)| {
+        let op = &ops[i];
+        let total = match op {
+            Operation::Add => n.iter().sum(),
+            Operation::Multiply => n
+                .iter()
+                .fold(0, |acc, num| if acc > 0 { acc * *num } else { *num }),
+        };
+        acc + total
+    })
+}
+
+pub fn do_homework(ops: &[Operation], nums: &[Vec<u64>]) -> u64 {
+    let mut results: Vec<u64> = nums[0].clone();
+
+    for row in nums.iter().skip(1) {
+        for (i, num) in row.iter().enumerate() {
+            // get op
+            let op = &ops[i];
+            // get num
+            // read current result
+            let current = results[i];
+            // apply op
+            let total = match op {
+                Operation::Add => current + num,
+                Operation::Multiply => current * num,
+            };
+            results[i] = total;
+        }
+    }
+
+    results.iter().sum()
+}
+
+// matrix transposition
+fn transpose<T>(v: Vec<Vec<T>>) -> Vec<Vec<T>> {
+    assert!(!v.is_empty());
+    let len = v[0].len();
+    let mut iters: Vec<_> = v.into_iter().map(|n| n.into_iter()).collect();
+    (0..len)
+        .map(|_| {
+            iters
+                .iter_mut()
+                .map(|n| n.next().expect("reached end of iter"))
+                .collect::<Vec<T>>()
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        Day06, Operation, do_homework, do_homework_part_2, parse_input, parse_input_part_2,
+    };
+
+    aoc_core::example_files!(Day06);
+
+    #[test]
+    fn it_should_parse_input() {
+        let input = "123 328  51 64 
+ 45 64  387 23 
+  6 98  215 314
+*   +   *   +  ";
+        let expected_ops = vec![
+            Operation::Multiply,
+            Operation::Add,
+            Operation::Multiply,
+            Operation::Add,
+        ];
+        // reversed
+        let expected_nums = vec![
+            vec![6, 98, 215, 314],
+            vec![45, 64, 387, 23],
+            vec![123, 328, 51, 64],
+        ];
+        assert_eq!(parse_input(input).unwrap(), (expected_ops, expected_nums));
+    }
+
+    #[test]
+    fn it_should_do_some_maths() {
+        let nums = vec![
+            vec![6, 98, 215, 314],
+            vec![45, 64, 387, 23],
+            vec![123, 328, 51, 64],
+        ];
+        let ops = vec![
+            Operation::Multiply,
+            Operation::Add,
+            Operation::Multiply,
+            Operation::Add,
+        ];
+
+        let result = do_homework(&ops, &nums);
+        assert_eq!(result, 4277556);
+    }
+
+    #[test]
+    fn it_should_parse_part_2() {
+        let input = "23  58 29
+56  73 87
+822 82 75
+337 66 17
+*   + ";
+
+        let expected_ops = vec![Operation::Multiply, Operation::Add];
+
+        let expected_nums = vec![vec![2583, 3623, 27], vec![5786, 8326], vec![2871, 9757]];
+        assert_eq!(
+            parse_input_part_2(input).unwrap(),
+            (expected_ops, expected_nums)
+        );
+        // 279083313 + 14112 + 28012347
+    }
+
+    #[test]
+    fn sum_homework_part_2() {
+        let ops = vec![Operation::Multiply];
+        let nums = vec![vec![1, 2, 3]];
+        assert_eq!(do_homework_part_2(&ops, &nums), 6);
+
+        let ops = vec![Operation::Multiply, Operation::Add];
+        let nums = vec![vec![1, 2, 3], vec![4, 5]];
+        assert_eq!(do_homework_part_2(&ops, &nums), 15);
+
+        let ops = vec![Operation::Multiply, Operation::Add, Operation::Multiply];
+        let nums = vec![vec![2583, 3623, 27], vec![5786, 8326], vec![2871, 9757]];
+
+        assert_eq!(do_homework_part_2(&ops, &nums), 280698102);
+    }
+
+    #[test]
+    fn it_should_report_bad_homework() {
+        let input = "123 328
+ 45 6x4
+*   +  ";
+        let err = parse_input(input).unwrap_err();
+        assert_eq!((err.line, err.column, err.len), (2, 5, 3));
+
+        let err = parse_input_part_2(input).unwrap_err();
+        assert_eq!((err.line, err.column), (2, 6));
+
+        let err = parse_input("1 2\n*   -").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 5));
+        assert_eq!(err.message, "expected * or +, got '-'");
+
+        let err = parse_input("1 2\n*").unwrap_err();
+        assert_eq!(err.message, "found 2 numbers for 1 operations");
+    }
+}
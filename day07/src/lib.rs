@@ -0,0 +1,341 @@
+use aoc_core::{ParseError, Solution};
+use std::collections::HashSet;
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    const DAY: u8 = 7;
+
+    type Input<'a> = Layout<'a>;
+    type Part1 = u16;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        Layout::try_from(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Self::Part1 {
+        input.calculate_splits()
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Self::Part2 {
+        input.calculate_part_2()
+    }
+}
+
+#[derive(Eq, PartialEq, Hash, Debug, Clone, Copy)]
+struct Position {
+    x: usize,
+    y: usize,
+}
+
+#[derive(PartialEq, Eq, Debug)]
+pub struct Layout<'a> {
+    width: usize,
+    height: usize,
+    start: Position,
+    splitters: HashSet<Position>,
+    map: Vec<&'a str>,
+}
+
+impl<'a> TryFrom<&'a str> for Layout<'a> {
+    type Error = ParseError;
+
+    fn try_from(input: &'a str) -> Result<Self, Self::Error> {
+        let mut layout = Layout {
+            width: 0,
+            height: 0,
+            start: Position { x: 0, y: 0 },
+            splitters: HashSet::new(),
+            map: vec![],
+        };
+        let mut start = None;
+
+        let mut count = 0;
+        for line in input.lines() {
+            if count > 0 && line.len() != layout.width {
+                return Err(ParseError::at(
+                    input,
+                    line,
+                    format!("row is {} wide, expected {}", line.len(), layout.width),
+                ));
+            }
+            layout.width = line.len();
+
+            for (i, c) in line.char_indices() {
+                let at = &line[i..i + c.len_utf8()];
+                match c {
+                    'S' if start.is_some() => {
+                        return Err(ParseError::at(input, at, "more than one start"));
+                    }
+                    'S' => start = Some(Position { x: i, y: count }),
+                    // beams split either side, so there has to be room for them
+                    '^' if i == 0 || i == line.len() - 1 => {
+                        return Err(ParseError::at(input, at, "splitter on the edge of the map"));
+                    }
+                    '^' => {
+                        layout.splitters.insert(Position { x: i, y: count });
+                    }
+                    '.' => {}
+                    _ => {
+                        return Err(ParseError::at(
+                            input,
+                            at,
+                            format!("expected '.', '^' or 'S', got '{c}'"),
+                        ));
+                    }
+                }
+            }
+            layout.map.push(line);
+            count += 1;
+        }
+        layout.height = count;
+        layout.start = start.ok_or_else(|| ParseError::at(input, input, "no start"))?;
+
+        Ok(layout)
+    }
+}
+
+impl<'a> Layout<'a> {
+    // only used by the slow search version of part 2
+    #[allow(dead_code)]
+    fn get_neighbours(&self, pos: Position) -> Vec<Position> {
+        let mut neighbours = vec![];
+
+        if pos.y >= self.height {
+            return vec![];
+        }
+        if self.splitters.contains(&Position {
+            x: pos.x,
+            y: pos.y + 1,
+        }) {
+            neighbours.push(Position {
+                x: pos.x - 1,
+                y: pos.y,
+            });
+            neighbours.push(Position {
+                x: pos.x + 1,
+                y: pos.y,
+            });
+        } else {
+            neighbours.push(Position {
+                x: pos.x,
+                y: pos.y + 1,
+            });
+        }
+
+        neighbours
+    }
+
+    pub fn calculate_splits(&self) -> u16 {
+        let mut beams = HashSet::from([self.start]);
+        let mut count = 0;
+        for _ in 0..self.height {
+            (count, beams) = self.step(beams, count);
+        }
+
+        count
+    }
+
+    fn step(&self, beams: HashSet<Position>, count: u16) -> (u16, HashSet<Position>) {
+        let mut updated_beams = HashSet::new();
+        let mut total = count;
+        // get next line from beam
+        // is (beam.x, beam.y + 1) a splitter?
+        for beam in beams {
+            if beam.y < self.height - 1 {
+                if self.splitters.contains(&Position {
+                    x: beam.x,
+                    y: beam.y + 1,
+                }) {
+                    updated_beams.insert(Position {
+                        x: beam.x - 1,
+                        y: beam.y + 1,
+                    });
+                    updated_beams.insert(Position {
+                        x: beam.x + 1,
+                        y: beam.y + 1,
+                    });
+
+                    total += 1;
+                } else {
+                    updated_beams.insert(Position {
+                        x: beam.x,
+                        y: beam.y + 1,
+                    });
+                }
+            }
+        }
+        // no = push new pos
+        // yes = increment and add (beam.x - 1, beam.y) and (beam.x + 1, beam.y)
+        // return beams
+        (total, updated_beams)
+    }
+
+    fn _calculate_part_2_search(&self) -> usize {
+        // breadth-first takes forever!
+        // todo: memoise
+        let mut queue = vec![];
+        let mut count = 0;
+
+        queue.push(self.start);
+
+        while !queue.is_empty() {
+            if let Some(current) = queue.pop() {
+                // memo
+                // reached end
+                if current.y == self.height - 1 {
+                    count += 1;
+                } else {
+                    let mut neighbours = self.get_neighbours(current);
+                    queue.append(&mut neighbours);
+                }
+            }
+        }
+
+        count
+    }
+
+    /// use array to count no. times position taken
+    /// go line by line through map so each position is only checked once
+    pub fn calculate_part_2(&self) -> usize {
+        let mut count = vec![0; self.width];
+
+        // add start
+        count[self.start.x] = 1;
+
+        for line in self.map.iter() {
+            for (col, c) in line.chars().enumerate() {
+                if c == '^' {
+                    // on splitter, update paths either side
+                    // and reset
+                    let current = count[col];
+                    count[col - 1] += current;
+                    count[col + 1] += current;
+                    count[col] = 0;
+                }
+            }
+        }
+
+        count.iter().sum()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::HashSet;
+
+    use crate::{Day07, Layout, Position};
+
+    const TEST_INPUT: &str = ".......S.......
+...............
+.......^.......
+...............
+......^.^......
+...............
+.....^.^.^.....
+...............
+....^.^...^....
+...............
+...^.^...^.^...
+...............
+..^...^.....^..
+...............
+.^.^.^.^.^...^.
+...............";
+
+    /// the example from the puzzle, as `Layout::try_from` should build it
+    fn example() -> Layout<'static> {
+        Layout {
+            width: 15,
+            height: 16,
+            start: Position { x: 7, y: 0 },
+            splitters: HashSet::from([
+                Position { x: 6, y: 4 },
+                Position { x: 10, y: 8 },
+                Position { x: 3, y: 10 },
+                Position { x: 8, y: 4 },
+                Position { x: 9, y: 10 },
+                Position { x: 7, y: 2 },
+                Position { x: 12, y: 12 },
+                Position { x: 1, y: 14 },
+                Position { x: 9, y: 6 },
+                Position { x: 5, y: 14 },
+                Position { x: 9, y: 14 },
+                Position { x: 7, y: 14 },
+                Position { x: 13, y: 14 },
+                Position { x: 2, y: 12 },
+                Position { x: 3, y: 14 },
+                Position { x: 7, y: 6 },
+                Position { x: 11, y: 10 },
+                Position { x: 6, y: 8 },
+                Position { x: 5, y: 6 },
+                Position { x: 4, y: 8 },
+                Position { x: 6, y: 12 },
+                Position { x: 5, y: 10 },
+            ]),
+            map: TEST_INPUT.lines().collect(),
+        }
+    }
+
+    aoc_core::example_files!(Day07);
+
+    #[test]
+    fn it_should_parse_to_layout() {
+        assert_eq!(Layout::try_from(TEST_INPUT).unwrap(), example());
+    }
+
+    #[test]
+    fn it_should_step() {
+        let layout = example();
+
+        let beams = HashSet::from([Position { x: 7, y: 1 }]);
+        assert_eq!(
+            layout.step(beams, 0),
+            (
+                1,
+                HashSet::from([Position { x: 6, y: 2 }, Position { x: 8, y: 2 }])
+            )
+        );
+
+        assert_eq!(
+            layout.step(
+                HashSet::from([Position { x: 6, y: 2 }, Position { x: 8, y: 2 }]),
+                1
+            ),
+            (
+                1,
+                HashSet::from([Position { x: 6, y: 3 }, Position { x: 8, y: 3 }])
+            )
+        )
+    }
+
+    #[test]
+    fn it_should_calculate() {
+        assert_eq!(example().calculate_splits(), 21);
+    }
+
+    #[test]
+    fn it_should_check_all_paths_search() {
+        assert_eq!(example()._calculate_part_2_search(), 40);
+    }
+
+    #[test]
+    fn it_should_check_all_paths() {
+        assert_eq!(example().calculate_part_2(), 40);
+    }
+
+    #[test]
+    fn it_should_report_bad_layouts() {
+        let err = Layout::try_from("..S..\n..x..").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 3));
+
+        let err = Layout::try_from("..S..\n^....").unwrap_err();
+        assert_eq!(err.message, "splitter on the edge of the map");
+
+        let err = Layout::try_from("..S..\n...").unwrap_err();
+        assert_eq!(err.message, "row is 3 wide, expected 5");
+
+        assert!(Layout::try_from(".....").is_err());
+    }
+}
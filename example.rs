@@ -1,18 +1,35 @@
-use std::{env::args, fs::read_to_string, io};
+use aoc_core::{ParseError, Solution};
 
-fn main() -> Result<(), io::Error> {
-    let args: Vec<String> = args().collect();
+pub struct Day00;
 
-    let filename = &args[1];
-    let input = read_to_string(filename)?;
+impl Solution for Day00 {
+    const DAY: u8 = 0;
 
-    Ok(())
+    type Input<'a> = &'a str;
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        Ok(input)
+    }
+
+    fn part1(_input: &Self::Input<'_>) -> Self::Part1 {
+        0
+    }
+
+    fn part2(_input: &Self::Input<'_>) -> Self::Part2 {
+        0
+    }
 }
 
 #[cfg(test)]
 mod test {
-    #[test]
-    fn it_should_do_the_example() {
-        assert_eq!(true, true);
+    use crate::Day00;
+
+    // TODO: paste in the example from the puzzle and its answers
+    // or put them in examples/dayNN/example.txt and use `aoc_core::example_files!(Day00);`
+    aoc_core::examples! {
+        Day00;
+        example: "" => (0, 0),
     }
 }